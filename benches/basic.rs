@@ -4,7 +4,7 @@ extern crate sysinfo;
 extern crate test;
 
 use sysinfo::get_current_pid;
-use sysinfo::{SystemExt};
+use sysinfo::{DiskExt, SystemExt};
 
 #[bench]
 fn bench_new(b: &mut test::Bencher) {
@@ -61,6 +61,37 @@ fn bench_refresh_process(b: &mut test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_refresh_disk(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new_all();
+
+    s.refresh_all();
+    let disks = s.get_disks_mut();
+    let disk = &mut disks[0];
+    b.iter(move || {
+        disk.refresh();
+    });
+}
+
+#[bench]
+fn bench_refresh_disks(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new_all();
+
+    s.refresh_all();
+    b.iter(move || {
+        s.refresh_disks();
+    });
+}
+
+#[bench]
+fn bench_refresh_disks_list(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new_all();
+
+    b.iter(move || {
+        s.refresh_disks_list();
+    });
+}
+
 #[bench]
 fn bench_refresh_networks(b: &mut test::Bencher) {
     let mut s = sysinfo::System::new_all();
@@ -79,6 +110,24 @@ fn bench_refresh_networks_list(b: &mut test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_refresh_block_devices(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new_all();
+
+    b.iter(move || {
+        s.refresh_block_devices();
+    });
+}
+
+#[bench]
+fn bench_refresh_block_devices_list(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new();
+
+    b.iter(move || {
+        s.refresh_block_devices_list();
+    });
+}
+
 #[bench]
 fn bench_refresh_memory(b: &mut test::Bencher) {
     let mut s = sysinfo::System::new();
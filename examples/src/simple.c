@@ -27,12 +27,11 @@ void print_process(CProcess process) {
     sysinfo_rstring_free(exe);
 }
 
-void check_tasks(CSystem system) {
+void check_threads(CSystem system) {
 #ifdef __linux__
-    bool task_loop(pid_t pid, CProcess process, void *data) {
+    bool thread_loop(pid_t tid, void *data) {
         (void)data;
-        printf("  ");
-        print_process(process);
+        printf("  thread[%d]\n", tid);
         return true;
     }
 
@@ -44,9 +43,9 @@ void check_tasks(CSystem system) {
     pthread_create(&thread, NULL, sleeping_func, NULL);
     sysinfo_refresh_system(system);
     CProcess process = sysinfo_get_process_by_pid(system, getpid());
-    printf("\n== Task(s) for current process: ==\n");
+    printf("\n== Thread(s) for current process: ==\n");
     print_process(process);
-    printf("Got %ld task(s)\n", sysinfo_process_get_tasks(process, task_loop, NULL));
+    printf("Got %ld thread(s)\n", sysinfo_process_get_threads(process, thread_loop, NULL));
 #else
     (void)system;
 #endif
@@ -83,7 +82,7 @@ int main() {
     // processes part
     i = 0;
     printf("For a total of %ld processes.\n", sysinfo_get_processes(system, process_loop, &i));
-    check_tasks(system);
+    check_threads(system);
     // we can now free the CSystem object.
     sysinfo_destroy(system);
     return 0;
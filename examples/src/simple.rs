@@ -13,7 +13,8 @@ use std::io::{self, BufRead, Write};
 use std::str::FromStr;
 use sysinfo::Signal::*;
 use sysinfo::{
-    NetworkExt, NetworksExt, Pid, ProcessExt, ProcessorExt, Signal, System, SystemExt, UserExt,
+    BlockDeviceExt, BlockDevicesExt, NetworkExt, NetworksExt, Pid, ProcessExt, ProcessorExt,
+    Signal, System, SystemExt, UserExt,
 };
 
 const signals: [Signal; 31] = [
@@ -65,6 +66,10 @@ fn print_help() {
         &mut io::stdout(),
         "refresh [pid]      : reloads corresponding process' information"
     );
+    writeln!(
+        &mut io::stdout(),
+        "refresh_disks      : reloads only disks' information"
+    );
     writeln!(
         &mut io::stdout(),
         "refresh_users      : reloads only users' information"
@@ -91,10 +96,18 @@ fn print_help() {
         &mut io::stdout(),
         "temperature        : Displays components' temperature"
     );
+    writeln!(
+        &mut io::stdout(),
+        "disks              : Displays disks' information"
+    );
     writeln!(
         &mut io::stdout(),
         "network            : Displays network' information"
     );
+    writeln!(
+        &mut io::stdout(),
+        "block_devices      : Displays block devices' I/O statistics"
+    );
     writeln!(
         &mut io::stdout(),
         "all                : Displays all process name and pid"
@@ -130,6 +143,11 @@ fn print_help() {
 fn interpret_input(input: &str, sys: &mut System) -> bool {
     match input.trim() {
         "help" => print_help(),
+        "refresh_disks" => {
+            writeln!(&mut io::stdout(), "Refreshing disk list...");
+            sys.refresh_disks_list();
+            writeln!(&mut io::stdout(), "Done.");
+        }
         "refresh_users" => {
             writeln!(&mut io::stdout(), "Refreshing user list...");
             sys.refresh_users_list();
@@ -243,6 +261,11 @@ fn interpret_input(input: &str, sys: &mut System) -> bool {
                 writeln!(&mut io::stdout(), "{:?}", component);
             }
         }
+        "disks" => {
+            for disk in sys.get_disks() {
+                writeln!(&mut io::stdout(), "{:?}", disk);
+            }
+        }
         "network" => {
             for (interface_name, data) in sys.get_networks().iter() {
                 writeln!(
@@ -256,6 +279,23 @@ fn interpret_input(input: &str, sys: &mut System) -> bool {
                 );
             }
         }
+        "block_devices" => {
+            for (name, device) in sys.get_block_devices().iter() {
+                writeln!(
+                    &mut io::stdout(),
+                    "{}:\n  reads  (new / total): {} / {}\n  writes (new / total): {} / {}\n  \
+                     await: {} ms, utilization: {}%, average queue size: {}",
+                    name,
+                    device.get_reads_completed(),
+                    device.get_total_reads_completed(),
+                    device.get_writes_completed(),
+                    device.get_total_writes_completed(),
+                    device.get_await(),
+                    device.get_utilization(),
+                    device.get_average_queue_size(),
+                );
+            }
+        }
         "show" => {
             writeln!(
                 &mut io::stdout(),
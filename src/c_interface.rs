@@ -17,6 +17,8 @@ pub type CProcess = *const c_void;
 pub type RString = *const c_char;
 /// Callback used by [`get_processes`][crate::System#method.get_processes].
 pub type ProcessLoop = extern "C" fn(pid: pid_t, process: CProcess, data: *mut c_void) -> bool;
+/// Callback used by [`threads`][crate::Process#method.threads].
+pub type ThreadLoop = extern "C" fn(tid: pid_t, data: *mut c_void) -> bool;
 
 /// Equivalent of [`System::new()`][crate::System#method.new].
 #[no_mangle]
@@ -119,6 +121,29 @@ pub extern "C" fn sysinfo_refresh_process(system: CSystem, pid: pid_t) {
     Box::into_raw(system);
 }
 
+/// Equivalent of [`System::refresh_disks()`][crate::System#method.refresh_disks].
+#[no_mangle]
+pub extern "C" fn sysinfo_refresh_disks(system: CSystem) {
+    assert!(!system.is_null());
+    let mut system: Box<System> = unsafe { Box::from_raw(system as *mut System) };
+    {
+        let system: &mut System = system.borrow_mut();
+        system.refresh_disks();
+    }
+    Box::into_raw(system);
+}
+
+/// Equivalent of [`System::refresh_disks_list()`][crate::System#method.refresh_disks_list].
+#[no_mangle]
+pub extern "C" fn sysinfo_refresh_disks_list(system: CSystem) {
+    assert!(!system.is_null());
+    let mut system: Box<System> = unsafe { Box::from_raw(system as *mut System) };
+    {
+        let system: &mut System = system.borrow_mut();
+        system.refresh_disks_list();
+    }
+    Box::into_raw(system);
+}
 
 /// Equivalent of [`System::get_total_memory()`][crate::System#method.get_total_memory].
 #[no_mangle]
@@ -289,27 +314,24 @@ pub extern "C" fn sysinfo_get_process_by_pid(system: CSystem, pid: pid_t) -> CPr
     ret
 }
 
-/// Equivalent of iterating over [`Process::tasks()`][crate::Process#method.tasks].
-///
-/// # /!\ WARNING /!\
-///
-/// While having this method processes, you should *never* call any refresh method!
+/// Equivalent of iterating over the ids of
+/// [`Process::threads()`][crate::Process#method.threads].
 #[cfg(target_os = "linux")]
 #[no_mangle]
-pub extern "C" fn sysinfo_process_get_tasks(
+pub extern "C" fn sysinfo_process_get_threads(
     process: CProcess,
-    fn_pointer: Option<ProcessLoop>,
+    fn_pointer: Option<ThreadLoop>,
     data: *mut c_void,
 ) -> size_t {
     assert!(!process.is_null());
     if let Some(fn_pointer) = fn_pointer {
-        let process = process as *const Process;
-        for (pid, process) in unsafe { (*process).tasks.iter() } {
-            if !fn_pointer(*pid, process as *const Process as CProcess, data) {
+        let threads = unsafe { (*(process as *const Process)).threads() };
+        for tid in threads.keys() {
+            if !fn_pointer(*tid, data) {
                 break;
             }
         }
-        unsafe { (*process).tasks.len() as size_t }
+        threads.len() as size_t
     } else {
         0
     }
@@ -4,11 +4,26 @@
 // Copyright (c) 2015 Guillaume Gomez
 //
 
+use BlockDevice;
+use BlockDevices;
+use BlockDevicesExt;
 use NetworkData;
 use Networks;
 use NetworksExt;
+use Process;
+use ProcessExt;
+use ProcessStatus;
 use UserExt;
 
+#[cfg(feature = "regex")]
+use regex::Regex;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
 /// Trait to have a common fallback for the [`Pid`][crate::Pid] type.
 pub trait AsU32 {
     /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
@@ -39,6 +54,16 @@ cfg_if! {
     }
 }
 
+/// User id.
+///
+/// On Windows, users don't have a numeric id so this is only relevant on unix-like targets.
+pub type Uid = u32;
+
+/// Group id.
+///
+/// On Windows, groups don't have a numeric id so this is only relevant on unix-like targets.
+pub type Gid = u32;
+
 macro_rules! impl_get_set {
     ($name:ident, $with:ident, $without:ident) => {
         doc_comment! {
@@ -104,6 +129,10 @@ assert_eq!(r.", stringify!($name), "(), false);
 /// ```
 /// use sysinfo::{RefreshKind, System, SystemExt};
 ///
+/// // We want everything except disks.
+/// let mut system = System::new_with_specifics(RefreshKind::everything().without_disks_list());
+///
+/// assert_eq!(system.get_disks().len(), 0);
 /// assert!(system.get_processes().len() > 0);
 /// ```
 ///
@@ -112,7 +141,11 @@ assert_eq!(r.", stringify!($name), "(), false);
 pub struct RefreshKind {
     networks: bool,
     networks_list: bool,
+    block_devices: bool,
+    block_devices_list: bool,
     processes: bool,
+    disks_list: bool,
+    disks: bool,
     memory: bool,
     cpu: bool,
     components: bool,
@@ -130,7 +163,11 @@ impl RefreshKind {
     ///
     /// assert_eq!(r.networks(), false);
     /// assert_eq!(r.networks_list(), false);
+    /// assert_eq!(r.block_devices(), false);
+    /// assert_eq!(r.block_devices_list(), false);
     /// assert_eq!(r.processes(), false);
+    /// assert_eq!(r.disks_list(), false);
+    /// assert_eq!(r.disks(), false);
     /// assert_eq!(r.memory(), false);
     /// assert_eq!(r.cpu(), false);
     /// assert_eq!(r.components(), false);
@@ -150,7 +187,11 @@ impl RefreshKind {
     ///
     /// assert_eq!(r.networks(), true);
     /// assert_eq!(r.networks_list(), true);
+    /// assert_eq!(r.block_devices(), true);
+    /// assert_eq!(r.block_devices_list(), true);
     /// assert_eq!(r.processes(), true);
+    /// assert_eq!(r.disks_list(), true);
+    /// assert_eq!(r.disks(), true);
     /// assert_eq!(r.memory(), true);
     /// assert_eq!(r.cpu(), true);
     /// assert_eq!(r.components(), true);
@@ -161,7 +202,11 @@ impl RefreshKind {
         RefreshKind {
             networks: true,
             networks_list: true,
+            block_devices: true,
+            block_devices_list: true,
             processes: true,
+            disks: true,
+            disks_list: true,
             memory: true,
             cpu: true,
             components: true,
@@ -172,7 +217,15 @@ impl RefreshKind {
 
     impl_get_set!(networks, with_networks, without_networks);
     impl_get_set!(networks_list, with_networks_list, without_networks_list);
+    impl_get_set!(block_devices, with_block_devices, without_block_devices);
+    impl_get_set!(
+        block_devices_list,
+        with_block_devices_list,
+        without_block_devices_list
+    );
     impl_get_set!(processes, with_processes, without_processes);
+    impl_get_set!(disks, with_disks, without_disks);
+    impl_get_set!(disks_list, with_disks_list, without_disks_list);
     impl_get_set!(memory, with_memory, without_memory);
     impl_get_set!(cpu, with_cpu, without_cpu);
     impl_get_set!(components, with_components, without_components);
@@ -221,6 +274,716 @@ impl<'a> IntoIterator for &'a Networks {
     }
 }
 
+/// Iterator over block devices.
+///
+/// It is returned by [`BlockDevices::iter`][crate::BlockDevices#method.iter].
+///
+/// ```no_run
+/// use sysinfo::{BlockDevicesExt, System, SystemExt};
+///
+/// let system = System::new_all();
+/// let block_devices_iter = system.get_block_devices().iter();
+/// ```
+pub struct BlockDevicesIter<'a> {
+    inner: std::collections::hash_map::Iter<'a, String, BlockDevice>,
+}
+
+impl<'a> BlockDevicesIter<'a> {
+    pub(crate) fn new(v: std::collections::hash_map::Iter<'a, String, BlockDevice>) -> Self {
+        BlockDevicesIter { inner: v }
+    }
+}
+
+impl<'a> Iterator for BlockDevicesIter<'a> {
+    type Item = (&'a String, &'a BlockDevice);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'a> IntoIterator for &'a BlockDevices {
+    type Item = (&'a String, &'a BlockDevice);
+    type IntoIter = BlockDevicesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Enum containing the different supported disks kinds.
+///
+/// This type is returned by [`DiskExt::get_kind`][crate::DiskExt#method.get_kind] and
+/// [`BlockDeviceExt::get_kind`][crate::BlockDeviceExt#method.get_kind].
+///
+/// ```no_run
+/// use sysinfo::{DiskExt, System, SystemExt};
+///
+/// let system = System::new_all();
+/// for disk in system.get_disks() {
+///     println!("{:?}: {:?}", disk.get_name(), disk.get_kind());
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiskKind {
+    /// HDD type.
+    HDD,
+    /// SSD type.
+    SSD,
+    /// Unknown type.
+    Unknown(isize),
+}
+
+impl From<isize> for DiskKind {
+    fn from(t: isize) -> DiskKind {
+        match t {
+            0 => DiskKind::SSD,
+            1 => DiskKind::HDD,
+            id => DiskKind::Unknown(id),
+        }
+    }
+}
+
+/// Status of a software RAID array (`md` devices on Linux).
+///
+/// It is returned by [`BlockDeviceExt::get_raid_status`][crate::BlockDeviceExt#method.get_raid_status].
+///
+/// ```no_run
+/// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (name, device) in s.get_block_devices() {
+///     if let Some(status) = device.get_raid_status() {
+///         if status.is_degraded() {
+///             println!("{} on {} is degraded", status.level, name);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaidStatus {
+    /// RAID level of the array (like `raid1`). It is empty if the array is inactive.
+    pub level: String,
+    /// `true` if the array is active.
+    pub active: bool,
+    /// Names of the member devices.
+    pub devices: Vec<String>,
+    /// Names of the member devices marked as faulty.
+    pub failed_devices: Vec<String>,
+    /// Number of devices the array should have.
+    pub expected_devices: u32,
+    /// Number of devices currently working in the array.
+    pub working_devices: u32,
+    /// Synchronization operation in progress, if any (like `resync`, `recovery` or `check`).
+    pub sync_action: Option<String>,
+    /// Progress (in %) of the synchronization operation. It is `None` if no operation is running
+    /// or if it is delayed.
+    pub sync_progress: Option<f32>,
+}
+
+impl RaidStatus {
+    /// Returns `true` if some devices of the array are missing or faulty.
+    pub fn is_degraded(&self) -> bool {
+        self.working_devices < self.expected_devices || !self.failed_devices.is_empty()
+    }
+}
+
+/// Type containing read and written bytes.
+///
+/// It is returned by [`ProcessExt::disk_usage`][crate::ProcessExt::disk_usage].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.get_processes() {
+///     let disk_usage = process.disk_usage();
+///     println!("[{}] read bytes   : new/total => {}/{} B",
+///         pid,
+///         disk_usage.read_bytes,
+///         disk_usage.total_read_bytes,
+///     );
+///     println!("[{}] written bytes: new/total => {}/{} B",
+///         pid,
+///         disk_usage.written_bytes,
+///         disk_usage.total_written_bytes,
+///     );
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiskUsage {
+    /// Total number of written bytes.
+    pub total_written_bytes: u64,
+    /// Number of written bytes since the last refresh.
+    pub written_bytes: u64,
+    /// Total number of read bytes.
+    pub total_read_bytes: u64,
+    /// Number of read bytes since the last refresh.
+    pub read_bytes: u64,
+}
+
+/// Type containing the CPU time consumed by a process.
+///
+/// Like for [`DiskUsage`], each time is provided as a total and as the difference since the
+/// last refresh.
+///
+/// It is returned by [`ProcessExt::cpu_time`][crate::ProcessExt::cpu_time].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.get_processes() {
+///     if let Some(cpu_time) = process.cpu_time() {
+///         println!("[{}] user time: new/total => {:?}/{:?}",
+///             pid,
+///             cpu_time.user_time,
+///             cpu_time.total_user_time,
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CpuTime {
+    /// Total time spent in user mode.
+    pub total_user_time: Duration,
+    /// Time spent in user mode since the last refresh.
+    pub user_time: Duration,
+    /// Total time spent in kernel mode.
+    pub total_system_time: Duration,
+    /// Time spent in kernel mode since the last refresh.
+    pub system_time: Duration,
+}
+
+/// Kind of an open file descriptor.
+///
+/// It is used by [`OpenFile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileKind {
+    /// Regular file.
+    File,
+    /// Directory.
+    Directory,
+    /// Character device (like a terminal or `/dev/null`).
+    CharDevice,
+    /// Block device.
+    BlockDevice,
+    /// Named pipe (FIFO).
+    Fifo,
+    /// Socket, with its inode number.
+    Socket(u64),
+    /// Anonymous pipe, with its inode number.
+    Pipe(u64),
+    /// Anonymous inode, with its type (like `eventfd`, `eventpoll` or `inotify`).
+    AnonInode(String),
+    /// Unknown kind.
+    Unknown,
+}
+
+/// Type containing information about a file descriptor opened by a process.
+///
+/// It is returned by [`ProcessExt::open_files`][crate::ProcessExt::open_files].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.get_process(1337) {
+///     for file in process.open_files().unwrap_or_default() {
+///         println!("{} => {:?} ({:?})", file.fd, file.target, file.kind);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenFile {
+    /// File descriptor number.
+    pub fd: u32,
+    /// What the file descriptor points to. It is a path for files, directories and devices and
+    /// a description of the object otherwise (like `socket:[12345]` or `anon_inode:[eventfd]`).
+    pub target: PathBuf,
+    /// Kind of the file descriptor.
+    pub kind: FileKind,
+    /// Flags of the file descriptor (like `O_RDONLY` or `O_CLOEXEC`), as given to `open`.
+    pub flags: u32,
+    /// Current offset in the file.
+    pub position: u64,
+}
+
+/// Type containing the detailed memory usage of a process (in kB).
+///
+/// Unlike [`ProcessExt::memory`][crate::ProcessExt::memory], it allows to know how much memory
+/// is shared with other processes. It is returned by
+/// [`ProcessExt::memory_details`][crate::ProcessExt::memory_details].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.get_process(1337) {
+///     if let Some(details) = process.memory_details() {
+///         println!("PSS: {} kB, USS: {} kB", details.pss, details.uss());
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryDetails {
+    /// Resident set size: memory currently in RAM, shared pages included.
+    pub rss: u64,
+    /// Proportional set size: like `rss` but each shared page is divided by the number of
+    /// processes sharing it.
+    pub pss: u64,
+    /// Clean pages shared with other processes.
+    pub shared_clean: u64,
+    /// Dirty pages shared with other processes.
+    pub shared_dirty: u64,
+    /// Clean pages only used by this process.
+    pub private_clean: u64,
+    /// Dirty pages only used by this process.
+    pub private_dirty: u64,
+    /// Memory swapped out.
+    pub swap: u64,
+    /// Proportional swap size: like `swap` but each shared page is divided by the number of
+    /// processes sharing it.
+    pub swap_pss: u64,
+}
+
+impl MemoryDetails {
+    /// Returns the unique set size: the memory which would be freed if the process was killed.
+    pub fn uss(&self) -> u64 {
+        self.private_clean + self.private_dirty
+    }
+}
+
+/// Type containing the breakdown of the memory usage of a process (in kB).
+///
+/// It is returned by [`ProcessExt::memory_breakdown`][crate::ProcessExt::memory_breakdown].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.get_processes() {
+///     if let Some(breakdown) = process.memory_breakdown() {
+///         if breakdown.swap > 0 {
+///             println!("[{}] {} kB swapped out", pid, breakdown.swap);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    /// Resident anonymous memory (heap, stack, etc).
+    pub rss_anon: u64,
+    /// Resident memory mapped from files (executables, libraries, etc).
+    pub rss_file: u64,
+    /// Resident shared memory (`tmpfs`, shared anonymous mappings, etc).
+    pub rss_shmem: u64,
+    /// Memory swapped out.
+    pub swap: u64,
+    /// Peak resident set size ("high water mark").
+    pub peak_memory: u64,
+    /// Peak virtual memory size.
+    pub peak_virtual_memory: u64,
+    /// Memory locked in RAM (with `mlock` for example).
+    pub locked: u64,
+    /// Memory used by the page tables.
+    pub page_tables: u64,
+}
+
+/// Type containing counters about the activity of a process: page faults, time spent by its
+/// waited-for children and context switches.
+///
+/// Like for [`DiskUsage`], each counter is provided as a total and as the difference since the
+/// last refresh. Children times are in milliseconds.
+///
+/// It is returned by [`ProcessExt::activity`][crate::ProcessExt::activity].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.get_processes() {
+///     if let Some(activity) = process.activity() {
+///         println!("[{}] major faults: new/total => {}/{}",
+///             pid,
+///             activity.major_faults,
+///             activity.total_major_faults,
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessActivity {
+    /// Total number of minor page faults (which didn't require loading a page from disk).
+    pub total_minor_faults: u64,
+    /// Number of minor page faults since the last refresh.
+    pub minor_faults: u64,
+    /// Total number of major page faults (which required loading a page from disk).
+    pub total_major_faults: u64,
+    /// Number of major page faults since the last refresh.
+    pub major_faults: u64,
+    /// Total time spent in user mode by the waited-for children of the process.
+    pub total_children_user_time: u64,
+    /// Time spent in user mode by the waited-for children of the process since the last refresh.
+    pub children_user_time: u64,
+    /// Total time spent in kernel mode by the waited-for children of the process.
+    pub total_children_system_time: u64,
+    /// Time spent in kernel mode by the waited-for children of the process since the last
+    /// refresh.
+    pub children_system_time: u64,
+    /// Total number of voluntary context switches (when the process waits for a resource).
+    pub total_voluntary_context_switches: u64,
+    /// Number of voluntary context switches since the last refresh.
+    pub voluntary_context_switches: u64,
+    /// Total number of involuntary context switches (when the process is preempted).
+    pub total_involuntary_context_switches: u64,
+    /// Number of involuntary context switches since the last refresh.
+    pub involuntary_context_switches: u64,
+}
+
+/// Type containing information about a memory mapping of a process.
+///
+/// It is returned by [`ProcessExt::memory_maps`][crate::ProcessExt::memory_maps].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.get_process(1337) {
+///     for map in process.memory_maps().unwrap_or_default() {
+///         println!("{:x}-{:x} {} {:?}: {} kB", map.start, map.end, map.permissions, map.path, map.rss);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryMap {
+    /// Start address of the mapping.
+    pub start: u64,
+    /// End address of the mapping.
+    pub end: u64,
+    /// Permissions of the mapping (like `r-xp`).
+    pub permissions: String,
+    /// Mapped file or pseudo-path (like `[heap]` or `[stack]`). It is empty for anonymous
+    /// mappings.
+    pub path: PathBuf,
+    /// Size of the mapping (in kB).
+    pub size: u64,
+    /// Resident set size of the mapping (in kB).
+    pub rss: u64,
+    /// Proportional set size of the mapping (in kB).
+    pub pss: u64,
+}
+
+/// Resources which can be limited for a process.
+///
+/// It is used by [`ResourceLimit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// Maximum CPU time (in seconds).
+    CpuTime,
+    /// Maximum size of the files created (in bytes).
+    FileSize,
+    /// Maximum size of the data segment (in bytes).
+    DataSize,
+    /// Maximum size of the stack (in bytes).
+    StackSize,
+    /// Maximum size of the core dump files (in bytes).
+    CoreFileSize,
+    /// Maximum resident set size (in bytes).
+    ResidentSet,
+    /// Maximum number of processes (or threads) of the user.
+    Processes,
+    /// Maximum number of open file descriptors.
+    OpenFiles,
+    /// Maximum memory which can be locked in RAM (in bytes).
+    LockedMemory,
+    /// Maximum size of the virtual memory (in bytes).
+    AddressSpace,
+    /// Maximum number of file locks.
+    FileLocks,
+    /// Maximum number of pending signals.
+    PendingSignals,
+    /// Maximum size of the POSIX message queues (in bytes).
+    MsgQueueSize,
+    /// Ceiling of the nice value.
+    NicePriority,
+    /// Ceiling of the real-time priority.
+    RealtimePriority,
+    /// Maximum CPU time without blocking for real-time processes (in microseconds).
+    RealtimeTimeout,
+}
+
+/// Value of a resource limit.
+///
+/// It is used by [`ResourceLimit`] and by
+/// [`ProcessExt::set_resource_limit`][crate::ProcessExt::set_resource_limit]. `Unlimited` is
+/// greater than any value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Limit {
+    /// The resource is limited to the given value (in the unit of the resource).
+    Value(u64),
+    /// The resource isn't limited.
+    Unlimited,
+}
+
+/// Type containing the soft and hard limits of a resource for a process.
+///
+/// It is returned by [`ProcessExt::resource_limits`][crate::ProcessExt::resource_limits].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, Resource, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.get_processes() {
+///     for limit in process.resource_limits().unwrap_or_default() {
+///         if limit.resource == Resource::OpenFiles {
+///             println!("[{}] soft: {:?}, hard: {:?}", pid, limit.soft, limit.hard);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimit {
+    /// Limited resource.
+    pub resource: Resource,
+    /// Limit enforced by the kernel.
+    pub soft: Limit,
+    /// Ceiling of the soft limit.
+    pub hard: Limit,
+}
+
+/// Type containing a cgroup a process belongs to.
+///
+/// It is returned by [`ProcessExt::cgroups`][crate::ProcessExt::cgroups].
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.get_processes() {
+///     for cgroup in process.cgroups().unwrap_or_default() {
+///         if cgroup.is_unified() {
+///             println!("[{}] {:?}", pid, cgroup.path);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cgroup {
+    /// Id of the hierarchy. It is always `0` for the unified (v2) hierarchy.
+    pub hierarchy_id: u32,
+    /// Controllers bound to the hierarchy (like `cpu` and `cpuacct`, or `name=systemd` for named
+    /// hierarchies). It is empty for the unified (v2) hierarchy.
+    pub controllers: Vec<String>,
+    /// Path of the cgroup relative to the mount point of the hierarchy.
+    pub path: PathBuf,
+}
+
+impl Cgroup {
+    /// Returns `true` if this cgroup is part of the unified (v2) hierarchy.
+    pub fn is_unified(&self) -> bool {
+        self.hierarchy_id == 0 && self.controllers.is_empty()
+    }
+}
+
+/// Protocol of a [`Socket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SocketProtocol {
+    /// TCP socket (IPv4 or IPv6).
+    Tcp,
+    /// UDP socket (IPv4 or IPv6).
+    Udp,
+    /// Unix domain socket.
+    Unix,
+}
+
+/// State of a [`Socket`].
+///
+/// UDP sockets are `Established` if they are connected and `Close` otherwise. Unix sockets are
+/// `Listen` if they accept connections, `SynSent` while connecting, `Established` once
+/// connected and `Close` otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SocketState {
+    /// The connection is open.
+    Established,
+    /// A connection request has been sent.
+    SynSent,
+    /// A connection request has been received and answered.
+    SynRecv,
+    /// The socket is closed and the connection is shutting down.
+    FinWait1,
+    /// The connection is closed and the socket is waiting for the remote end to shut down.
+    FinWait2,
+    /// The socket is waiting after close to handle packets still in the network.
+    TimeWait,
+    /// The socket isn't being used.
+    Close,
+    /// The remote end has shut down and is waiting for the socket to close.
+    CloseWait,
+    /// The remote end has shut down and the socket is closed. Waiting for acknowledgement.
+    LastAck,
+    /// The socket is listening for incoming connections.
+    Listen,
+    /// Both sockets are shut down but not all the data has been sent.
+    Closing,
+    /// A connection request has been received (but the socket isn't created yet).
+    NewSynRecv,
+    /// Unknown state.
+    Unknown(u32),
+}
+
+impl From<u32> for SocketState {
+    fn from(state: u32) -> SocketState {
+        match state {
+            1 => SocketState::Established,
+            2 => SocketState::SynSent,
+            3 => SocketState::SynRecv,
+            4 => SocketState::FinWait1,
+            5 => SocketState::FinWait2,
+            6 => SocketState::TimeWait,
+            7 => SocketState::Close,
+            8 => SocketState::CloseWait,
+            9 => SocketState::LastAck,
+            10 => SocketState::Listen,
+            11 => SocketState::Closing,
+            12 => SocketState::NewSynRecv,
+            x => SocketState::Unknown(x),
+        }
+    }
+}
+
+/// Type containing information about a socket.
+///
+/// It is returned by [`SystemExt::get_sockets`][crate::SystemExt::get_sockets].
+///
+/// ```no_run
+/// use sysinfo::{SocketState, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for socket in s.get_sockets() {
+///     if socket.state == SocketState::Listen {
+///         println!("{:?} {:?} => {:?}", socket.protocol, socket.local_address, socket.pids);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Socket {
+    /// Protocol of the socket.
+    pub protocol: SocketProtocol,
+    /// Local address of the socket. It is `None` for Unix sockets.
+    pub local_address: Option<SocketAddr>,
+    /// Remote address of the socket. It is `None` for Unix sockets.
+    pub remote_address: Option<SocketAddr>,
+    /// Path of a Unix socket. Abstract sockets start with `@`. It is `None` for unnamed Unix
+    /// sockets and for the other protocols.
+    pub path: Option<PathBuf>,
+    /// State of the socket.
+    pub state: SocketState,
+    /// Inode of the socket, as found in [`FileKind::Socket`].
+    pub inode: u64,
+    /// User id of the owner of the socket. It is `None` for Unix sockets.
+    pub uid: Option<Uid>,
+    /// Number of bytes in the transmit queue. Always `0` for Unix sockets.
+    pub transmit_queue: u64,
+    /// Number of bytes in the receive queue. Always `0` for Unix sockets.
+    pub receive_queue: u64,
+    /// Pids of the processes with this socket open, sorted. Only the processes returned by
+    /// [`SystemExt::get_processes`][crate::SystemExt::get_processes] are checked.
+    pub pids: Vec<Pid>,
+}
+
+/// Kind of a Linux namespace.
+///
+/// It is used by [`Namespace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NamespaceKind {
+    /// Cgroup root directory.
+    Cgroup,
+    /// System V IPC and POSIX message queues.
+    Ipc,
+    /// Mount points.
+    Mnt,
+    /// Network devices, stacks, ports, etc.
+    Net,
+    /// Process ids.
+    Pid,
+    /// Boot and monotonic clocks.
+    Time,
+    /// User and group ids.
+    User,
+    /// Hostname and NIS domain name.
+    Uts,
+}
+
+/// Type identifying a namespace. Processes in the same namespace have the same `Namespace`.
+///
+/// It is returned by [`ProcessExt::namespaces`][crate::ProcessExt::namespaces].
+///
+/// ```no_run
+/// use sysinfo::{NamespaceKind, ProcessExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.get_process(1337) {
+///     for namespace in process.namespaces().unwrap_or_default() {
+///         if namespace.kind == NamespaceKind::Net {
+///             println!("network namespace: {}", namespace.id);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Namespace {
+    /// Kind of the namespace.
+    pub kind: NamespaceKind,
+    /// Inode number of the namespace.
+    pub id: u64,
+}
+
+/// Scheduling policy of a process.
+///
+/// It is returned by [`ProcessExt::scheduling_policy`][crate::ProcessExt::scheduling_policy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchedulingPolicy {
+    /// Default time-sharing policy (`SCHED_OTHER`).
+    Other,
+    /// Time-sharing policy for CPU-intensive processes (`SCHED_BATCH`).
+    Batch,
+    /// Policy for processes only running when the system is idle (`SCHED_IDLE`).
+    Idle,
+    /// Real-time first in, first out policy (`SCHED_FIFO`).
+    Fifo,
+    /// Real-time round-robin policy (`SCHED_RR`).
+    RoundRobin,
+    /// Real-time deadline policy (`SCHED_DEADLINE`).
+    Deadline,
+    /// Unknown policy.
+    Unknown(u32),
+}
+
+impl From<u32> for SchedulingPolicy {
+    fn from(policy: u32) -> SchedulingPolicy {
+        match policy {
+            0 => SchedulingPolicy::Other,
+            1 => SchedulingPolicy::Fifo,
+            2 => SchedulingPolicy::RoundRobin,
+            3 => SchedulingPolicy::Batch,
+            5 => SchedulingPolicy::Idle,
+            6 => SchedulingPolicy::Deadline,
+            x => SchedulingPolicy::Unknown(x),
+        }
+    }
+}
+
+impl SchedulingPolicy {
+    /// Returns `true` if this is a real-time policy.
+    pub fn is_realtime(&self) -> bool {
+        matches!(
+            *self,
+            SchedulingPolicy::Fifo | SchedulingPolicy::RoundRobin | SchedulingPolicy::Deadline
+        )
+    }
+}
+
 /// An enum representing signal on UNIX-like systems.
 #[repr(C)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
@@ -293,6 +1056,79 @@ pub enum Signal {
     Sys = 31,
 }
 
+/// Identifier of a process which, unlike its [`Pid`], cannot be reused by another process.
+///
+/// It is returned by [`ProcessExt::process_id`][crate::ProcessExt::process_id] and can be used
+/// as a key to keep track of processes across refreshes.
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use sysinfo::{ProcessExt, ProcessId, System, SystemExt};
+///
+/// let mut memory: HashMap<ProcessId, Vec<u64>> = HashMap::new();
+/// let mut s = System::new();
+/// for _ in 0..10 {
+///     s.refresh_processes();
+///     for process in s.get_processes().values() {
+///         memory.entry(process.process_id()).or_default().push(process.memory());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProcessId {
+    /// Pid of the process.
+    pub pid: Pid,
+    /// Start time of the process. On Linux, it is in clock ticks since boot, otherwise it is the
+    /// same as [`ProcessExt::start_time`][crate::ProcessExt::start_time].
+    pub start_time: u64,
+    /// Id of the boot during which the process was started, so that processes from different
+    /// boots are not confused. It is `0` if it isn't available (only Linux provides it for the
+    /// moment).
+    pub boot_id: u128,
+}
+
+/// Error returned by [`ProcessExt::try_kill`][crate::ProcessExt::try_kill].
+///
+/// ```no_run
+/// use sysinfo::{KillError, ProcessExt, Signal, System, SystemExt};
+///
+/// let s = System::new();
+/// if let Some(process) = s.get_process(1337) {
+///     match process.try_kill(Signal::Term) {
+///         Ok(()) => println!("signal sent"),
+///         Err(KillError::NoSuchProcess) => println!("process already exited"),
+///         Err(e) => eprintln!("failed to send signal: {}", e),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillError {
+    /// The process doesn't exist (anymore). Matches `ESRCH`.
+    NoSuchProcess,
+    /// The caller isn't allowed to send a signal to the process. Matches `EPERM`.
+    PermissionDenied,
+    /// The signal is invalid. Matches `EINVAL`.
+    InvalidSignal,
+    /// Sending this signal isn't supported on this platform.
+    Unsupported,
+    /// Any other error, with its OS error code (or exit code on Windows).
+    Other(i32),
+}
+
+impl fmt::Display for KillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            KillError::NoSuchProcess => write!(f, "no such process"),
+            KillError::PermissionDenied => write!(f, "permission denied"),
+            KillError::InvalidSignal => write!(f, "invalid signal"),
+            KillError::Unsupported => write!(f, "not supported on this platform"),
+            KillError::Other(code) => write!(f, "failed to send signal (error {})", code),
+        }
+    }
+}
+
+impl Error for KillError {}
+
 /// A struct representing system load average value.
 ///
 /// It is returned by [`SystemExt::get_load_average`][crate::SystemExt::get_load_average].
@@ -334,9 +1170,19 @@ pub struct LoadAvg {
 pub struct User {
     pub(crate) name: String,
     pub(crate) groups: Vec<String>,
+    pub(crate) uid: Uid,
+    pub(crate) gid: Gid,
 }
 
 impl UserExt for User {
+    fn get_uid(&self) -> Uid {
+        self.uid
+    }
+
+    fn get_gid(&self) -> Gid {
+        self.gid
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -345,3 +1191,353 @@ impl UserExt for User {
         &self.groups
     }
 }
+
+/// Criteria used to sort processes in
+/// [`SystemExt::top_processes`][crate::SystemExt::top_processes].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSort {
+    /// Sort by CPU usage.
+    CpuUsage,
+    /// Sort by memory usage.
+    Memory,
+    /// Sort by number of bytes read and written on disk since the last refresh.
+    DiskUsage,
+}
+
+impl ProcessSort {
+    pub(crate) fn get_value(self, process: &Process) -> f64 {
+        match self {
+            ProcessSort::CpuUsage => process.cpu_usage() as f64,
+            ProcessSort::Memory => process.memory() as f64,
+            ProcessSort::DiskUsage => {
+                let usage = process.disk_usage();
+                (usage.read_bytes + usage.written_bytes) as f64
+            }
+        }
+    }
+}
+
+/// A process with the value it is sorted by in
+/// [`SystemExt::top_processes`][crate::SystemExt::top_processes].
+pub(crate) struct RankedProcess<'a> {
+    pub(crate) value: f64,
+    pub(crate) process: &'a Process,
+}
+
+impl<'a> PartialEq for RankedProcess<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for RankedProcess<'a> {}
+
+impl<'a> PartialOrd for RankedProcess<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for RankedProcess<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `NaN` values are considered equal to everything.
+        self.value
+            .partial_cmp(&other.value)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Query used to filter processes with
+/// [`SystemExt::find_processes`][crate::SystemExt::find_processes] and
+/// [`SystemExt::top_processes`][crate::SystemExt::top_processes].
+///
+/// A process matches the query if it matches all of its criteria. The name and the executable
+/// path can be matched with glob patterns (with `*` and `?`), or with regular expressions if the
+/// `regex` feature is enabled.
+///
+/// ```no_run
+/// use sysinfo::{ProcessExt, ProcessQuery, System, SystemExt};
+///
+/// let s = System::new_all();
+/// let query = ProcessQuery::new()
+///     .name("firefox*")
+///     .min_memory(100_000)
+///     .filter(|p| !p.cmd().is_empty());
+/// for process in s.find_processes(&query) {
+///     println!("{} {}", process.pid(), process.name());
+/// }
+/// ```
+#[derive(Default)]
+pub struct ProcessQuery<'a> {
+    pub(crate) name: Option<String>,
+    pub(crate) exe: Option<String>,
+    #[cfg(feature = "regex")]
+    pub(crate) name_regex: Option<Regex>,
+    #[cfg(feature = "regex")]
+    pub(crate) exe_regex: Option<Regex>,
+    pub(crate) cmd: Option<String>,
+    pub(crate) user_id: Option<Uid>,
+    pub(crate) parent: Option<Pid>,
+    pub(crate) status: Option<ProcessStatus>,
+    pub(crate) cgroup: Option<PathBuf>,
+    pub(crate) min_cpu_usage: Option<f32>,
+    pub(crate) min_memory: Option<u64>,
+    pub(crate) filters: Vec<ProcessFilter<'a>>,
+}
+
+type ProcessFilter<'a> = Box<dyn Fn(&Process) -> bool + 'a>;
+
+impl<'a> ProcessQuery<'a> {
+    /// Creates a query matching all processes.
+    pub fn new() -> ProcessQuery<'a> {
+        ProcessQuery::default()
+    }
+
+    /// Only matches processes whose name matches the glob `pattern` (it isn't a regular
+    /// expression). In the pattern, `*` matches any sequence of characters and `?` matches any
+    /// character, the whole name has to match. For example, `"*sh"` matches `"bash"` and
+    /// `"zsh"` but not `"sshd"`.
+    pub fn name(mut self, pattern: &str) -> Self {
+        self.name = Some(pattern.to_owned());
+        self
+    }
+
+    /// Only matches processes whose executable path matches the glob `pattern`. The pattern
+    /// accepts the same wildcards as [`ProcessQuery::name`].
+    pub fn exe(mut self, pattern: &str) -> Self {
+        self.exe = Some(pattern.to_owned());
+        self
+    }
+
+    /// Only matches processes whose name matches the regular expression `regex`. Like
+    /// [`Regex::is_match`], it matches if any part of the name matches (use `^` and `$` to
+    /// match the whole name).
+    ///
+    /// It is only available if the `regex` feature is enabled.
+    ///
+    /// ```no_run
+    /// extern crate regex;
+    /// extern crate sysinfo;
+    ///
+    /// use regex::Regex;
+    /// use sysinfo::{ProcessExt, ProcessQuery, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let query = ProcessQuery::new().name_regex(Regex::new("^(ba|z)sh$").unwrap());
+    /// for process in s.find_processes(&query) {
+    ///     println!("{} {}", process.pid(), process.name());
+    /// }
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn name_regex(mut self, regex: Regex) -> Self {
+        self.name_regex = Some(regex);
+        self
+    }
+
+    /// Only matches processes whose executable path matches the regular expression `regex`,
+    /// like [`ProcessQuery::name_regex`].
+    ///
+    /// It is only available if the `regex` feature is enabled.
+    #[cfg(feature = "regex")]
+    pub fn exe_regex(mut self, regex: Regex) -> Self {
+        self.exe_regex = Some(regex);
+        self
+    }
+
+    /// Only matches processes whose command line (with its arguments separated by spaces)
+    /// contains `text`.
+    pub fn cmd_contains(mut self, text: &str) -> Self {
+        self.cmd = Some(text.to_owned());
+        self
+    }
+
+    /// Only matches processes whose effective user id is `uid`.
+    pub fn user_id(mut self, uid: Uid) -> Self {
+        self.user_id = Some(uid);
+        self
+    }
+
+    /// Only matches the direct children of the process `pid`.
+    pub fn parent(mut self, pid: Pid) -> Self {
+        self.parent = Some(pid);
+        self
+    }
+
+    /// Only matches processes with the given `status`.
+    pub fn status(mut self, status: ProcessStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only matches processes which are in the cgroup `path` (or in one of its sub-cgroups).
+    ///
+    /// The cgroups of the processes are read when the query is run, see
+    /// [`ProcessExt::cgroups`][crate::ProcessExt::cgroups].
+    pub fn cgroup<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.cgroup = Some(path.into());
+        self
+    }
+
+    /// Only matches processes using at least `cpu_usage` % of CPU.
+    pub fn min_cpu_usage(mut self, cpu_usage: f32) -> Self {
+        self.min_cpu_usage = Some(cpu_usage);
+        self
+    }
+
+    /// Only matches processes using at least `memory` kB of memory.
+    pub fn min_memory(mut self, memory: u64) -> Self {
+        self.min_memory = Some(memory);
+        self
+    }
+
+    /// Only matches processes for which `filter` returns `true`. It can be called more than
+    /// once to add more filters.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessQuery};
+    ///
+    /// let query = ProcessQuery::new().filter(|p| p.environ().iter().any(|e| e == "DEBUG=1"));
+    /// ```
+    pub fn filter<F: Fn(&Process) -> bool + 'a>(mut self, filter: F) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Returns `true` if `process` matches all the criteria of the query.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessQuery, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let query = ProcessQuery::new().name("bash");
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{}", query.matches(process));
+    /// }
+    /// ```
+    pub fn matches(&self, process: &Process) -> bool {
+        if let Some(ref name) = self.name {
+            if !wildcard_match(name, process.name()) {
+                return false;
+            }
+        }
+        if let Some(ref exe) = self.exe {
+            if !wildcard_match(exe, &process.exe().to_string_lossy()) {
+                return false;
+            }
+        }
+        #[cfg(feature = "regex")]
+        {
+            if let Some(ref regex) = self.name_regex {
+                if !regex.is_match(process.name()) {
+                    return false;
+                }
+            }
+            if let Some(ref regex) = self.exe_regex {
+                if !regex.is_match(&process.exe().to_string_lossy()) {
+                    return false;
+                }
+            }
+        }
+        if let Some(ref cmd) = self.cmd {
+            if !process.cmd().join(" ").contains(cmd.as_str()) {
+                return false;
+            }
+        }
+        if self.user_id.is_some() && process.user_id() != self.user_id {
+            return false;
+        }
+        if self.parent.is_some() && process.parent() != self.parent {
+            return false;
+        }
+        if self.status.is_some() && Some(process.status()) != self.status {
+            return false;
+        }
+        if let Some(min_cpu_usage) = self.min_cpu_usage {
+            if process.cpu_usage() < min_cpu_usage {
+                return false;
+            }
+        }
+        if let Some(min_memory) = self.min_memory {
+            if process.memory() < min_memory {
+                return false;
+            }
+        }
+        // The cgroups are read from the system so it's done last.
+        if let Some(ref path) = self.cgroup {
+            let cgroups = process.cgroups().unwrap_or_default();
+            if !cgroups.iter().any(|cgroup| cgroup.path.starts_with(path)) {
+                return false;
+            }
+        }
+        self.filters.iter().all(|filter| filter(process))
+    }
+}
+
+/// Returns `true` if the whole `text` matches the glob `pattern`, where `*` matches any
+/// sequence of characters and `?` matches any character.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and of the text it is currently matched up to.
+    let mut star = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // Make the last `*` match one more character.
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, t));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_wildcard_match() {
+        // Empty pattern.
+        assert!(wildcard_match("", ""));
+        assert!(!wildcard_match("", "bash"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("?", ""));
+
+        // The whole text has to match.
+        assert!(wildcard_match("bash", "bash"));
+        assert!(!wildcard_match("bash", "bashrc"));
+        assert!(!wildcard_match("*sh", "sshd"));
+        assert!(wildcard_match("*sh", "zsh"));
+        assert!(wildcard_match("ba?h", "bash"));
+        assert!(!wildcard_match("ba?h", "bah"));
+
+        // Consecutive `*`.
+        assert!(wildcard_match("**", "bash"));
+        assert!(wildcard_match("b**h", "bash"));
+        assert!(wildcard_match("***", ""));
+        assert!(!wildcard_match("b**x", "bash"));
+
+        // Backtracking.
+        assert!(wildcard_match("a*b*c", "aXbYbZc"));
+        assert!(wildcard_match("a*b*c", "abbbc"));
+        assert!(!wildcard_match("a*b*c", "aXbYbZ"));
+        assert!(wildcard_match("*a*a", "aaa"));
+        assert!(!wildcard_match("*ab", "aba"));
+
+        // `?` matches a character, not a byte.
+        assert!(wildcard_match("h?llo", "héllo"));
+        assert!(wildcard_match("*ö*", "grün-ö-weiß"));
+        assert!(!wildcard_match("h??llo", "héllo"));
+        assert!(wildcard_match("進?", "進捗"));
+    }
+}
@@ -4,18 +4,29 @@
 // Copyright (c) 2020 Guillaume Gomez
 //
 
+use BlockDevice;
+use BlockDeviceExt;
+use BlockDevices;
+use BlockDevicesExt;
 use Component;
 use ComponentExt;
+use Disk;
+use DiskExt;
 use NetworkData;
 use NetworkExt;
 use Networks;
 use NetworksExt;
 use Process;
 use ProcessExt;
+use ProcessHandle;
+use ProcessHandleExt;
+use ProcessQuery;
 use Processor;
 use ProcessorExt;
 use System;
 use SystemExt;
+use Thread;
+use ThreadExt;
 
 use std::fmt;
 
@@ -42,8 +53,10 @@ impl fmt::Debug for System {
             .field("free swap", &self.get_free_swap())
             .field("nb CPUs", &self.get_processors().len())
             .field("nb network interfaces", &self.get_networks().iter().count())
+            .field("nb block devices", &self.get_block_devices().iter().count())
             .field("nb processes", &self.get_processes().len())
             .field("nb components", &self.get_components().len())
+            .field("nb disks", &self.get_disks().len())
             .finish()
     }
 }
@@ -63,10 +76,80 @@ impl fmt::Debug for Process {
             .field("CPU usage", &self.cpu_usage())
             .field("status", &self.status())
             .field("root", &self.root())
+            .field("disk_usage", &self.disk_usage())
+            .field("user id", &self.user_id())
+            .field("group id", &self.group_id())
+            .field("number of threads", &self.threads().len())
             .finish()
     }
 }
 
+impl fmt::Debug for ProcessHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("ProcessHandle");
+        f.field("pid", &self.pid());
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        f.field("pidfd", &self.pidfd());
+        f.finish()
+    }
+}
+
+impl<'a> fmt::Debug for ProcessQuery<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("ProcessQuery");
+        f.field("name", &self.name).field("exe", &self.exe);
+        #[cfg(feature = "regex")]
+        f.field("name regex", &self.name_regex)
+            .field("exe regex", &self.exe_regex);
+        f.field("cmd", &self.cmd)
+            .field("user id", &self.user_id)
+            .field("parent", &self.parent)
+            .field("status", &self.status)
+            .field("cgroup", &self.cgroup)
+            .field("min CPU usage", &self.min_cpu_usage)
+            .field("min memory", &self.min_memory)
+            .field("number of filters", &self.filters.len())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Thread {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Thread")
+            .field("tid", &self.tid())
+            .field("name", &self.name())
+            .field("status", &self.status())
+            .field("CPU usage", &self.cpu_usage())
+            .field(
+                "voluntary context switches",
+                &self.voluntary_context_switches(),
+            )
+            .field(
+                "involuntary context switches",
+                &self.involuntary_context_switches(),
+            )
+            .finish()
+    }
+}
+
+impl fmt::Debug for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Disk({:?})[FS: {:?}][Type: {:?}] mounted on {:?} ({}): {}/{} B, {}/{} inodes",
+            self.get_name(),
+            String::from_utf8_lossy(self.get_file_system()),
+            self.get_kind(),
+            self.get_mount_point(),
+            self.get_mount_options().join(","),
+            self.get_available_space(),
+            self.get_total_space(),
+            self.get_free_inodes(),
+            self.get_total_inodes()
+        )
+    }
+}
+
 impl fmt::Debug for Component {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(critical) = self.get_critical() {
@@ -127,3 +210,39 @@ impl fmt::Debug for NetworkData {
             .finish()
     }
 }
+
+impl fmt::Debug for BlockDevices {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BlockDevices {{ {} }}",
+            self.iter()
+                .map(|x| format!("{:?}", x))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Debug for BlockDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockDevice")
+            .field("kind", &self.get_kind())
+            .field("model", &self.get_model())
+            .field("size", &self.get_size())
+            .field("parent", &self.get_parent())
+            .field("holders", &self.get_holders())
+            .field("RAID status", &self.get_raid_status())
+            .field("reads", &self.get_reads_completed())
+            .field("total reads", &self.get_total_reads_completed())
+            .field("writes", &self.get_writes_completed())
+            .field("total writes", &self.get_total_writes_completed())
+            .field("sectors read", &self.get_sectors_read())
+            .field("sectors written", &self.get_sectors_written())
+            .field("I/Os in progress", &self.get_ios_in_progress())
+            .field("await", &self.get_await())
+            .field("utilization", &self.get_utilization())
+            .field("average queue size", &self.get_average_queue_size())
+            .finish()
+    }
+}
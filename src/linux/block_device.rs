@@ -0,0 +1,679 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use BlockDeviceExt;
+use BlockDevicesExt;
+use BlockDevicesIter;
+use DiskKind;
+use RaidStatus;
+
+use super::system::get_all_data;
+
+/// Block devices.
+///
+/// ```no_run
+/// use sysinfo::{BlockDevicesExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// let block_devices = s.get_block_devices();
+/// ```
+pub struct BlockDevices {
+    devices: HashMap<String, BlockDevice>,
+}
+
+impl BlockDevices {
+    pub(crate) fn new() -> Self {
+        BlockDevices {
+            devices: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, refresh_list: bool) {
+        // The format of this file is described in the kernel documentation
+        // (`Documentation/admin-guide/iostats.rst`):
+        //
+        //  8       0 sda 3390 1178 241386 3219 2053 3152 87512 4370 0 5768 7590 ...
+        //
+        // Newer kernels add discard and flush fields at the end of each line, we don't use them.
+        let content = match get_all_data("/proc/diskstats", 16_385) {
+            Ok(content) => content,
+            Err(_) => return,
+        };
+        let now = Instant::now();
+        let mut found = Vec::new();
+        let mut raid_statuses = get_raid_statuses();
+
+        for line in content.lines() {
+            let mut fields = line.split_whitespace().skip(2);
+            let name = match fields.next() {
+                Some(name) => name,
+                None => continue,
+            };
+            let mut stats = [0u64; 11];
+            let mut nb_stats = 0;
+            for (stat, field) in stats.iter_mut().zip(fields) {
+                *stat = field.parse().unwrap_or(0);
+                nb_stats += 1;
+            }
+            if nb_stats < stats.len() {
+                continue;
+            }
+            let raid_status = raid_statuses.remove(name);
+            if refresh_list {
+                found.push(name.to_owned());
+                self.devices
+                    .entry(name.to_owned())
+                    .or_insert_with(|| BlockDevice::new(&stats, now))
+                    .update(name, &stats, raid_status, now);
+            } else if let Some(device) = self.devices.get_mut(name) {
+                device.update(name, &stats, raid_status, now);
+            }
+        }
+        if refresh_list {
+            self.devices.retain(|name, _| found.contains(name));
+        }
+    }
+}
+
+impl BlockDevicesExt for BlockDevices {
+    fn iter(&self) -> BlockDevicesIter<'_> {
+        BlockDevicesIter::new(self.devices.iter())
+    }
+
+    fn get(&self, name: &str) -> Option<&BlockDevice> {
+        self.devices.get(name)
+    }
+
+    fn refresh(&mut self) {
+        self.update(false);
+    }
+
+    fn refresh_block_devices_list(&mut self) {
+        self.update(true);
+    }
+}
+
+/// Returns the sysfs directory of the given block device.
+fn get_sys_path(name: &str) -> Option<PathBuf> {
+    // Slashes in device names (like `cciss/c0d0`) are replaced by `!` in sysfs.
+    fs::canonicalize(Path::new("/sys/class/block").join(name.replace('/', "!"))).ok()
+}
+
+/// Returns the sysfs directory of the disk the given block device belongs to. If the device
+/// isn't a partition, its own directory is returned.
+fn get_sys_disk_path(sys_path: &Path) -> Option<PathBuf> {
+    if sys_path.join("partition").exists() {
+        sys_path.parent().map(|p| p.to_path_buf())
+    } else {
+        Some(sys_path.to_path_buf())
+    }
+}
+
+/// Returns the names of the block devices in the given sysfs directory (like `slaves`).
+fn read_sys_dir(path: &Path) -> Vec<String> {
+    let mut names = match fs::read_dir(path) {
+        Ok(dir) => dir
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .map(|name| name.replace('!', "/"))
+            .collect::<Vec<_>>(),
+        Err(_) => Vec::new(),
+    };
+    names.sort();
+    names
+}
+
+/// Returns the partitions of the disk with the given sysfs directory.
+fn get_partitions(sys_path: &Path) -> Vec<String> {
+    let mut partitions = match fs::read_dir(sys_path) {
+        Ok(dir) => dir
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                if !entry.path().join("partition").exists() {
+                    return None;
+                }
+                entry.file_name().into_string().ok()
+            })
+            .map(|name| name.replace('!', "/"))
+            .collect::<Vec<_>>(),
+        Err(_) => Vec::new(),
+    };
+    partitions.sort();
+    partitions
+}
+
+fn read_sys_file(path: &Path) -> Option<String> {
+    get_all_data(path, 256).ok().map(|s| s.trim().to_owned())
+}
+
+fn get_kind(sys_disk_path: &Path) -> DiskKind {
+    match read_sys_file(&sys_disk_path.join("queue/rotational"))
+        .and_then(|s| s.parse::<isize>().ok())
+    {
+        Some(rotational) => DiskKind::from(rotational),
+        None => DiskKind::Unknown(-1),
+    }
+}
+
+/// Returns the kind of the given block device (like `sda1` or `dm-0`).
+pub(crate) fn get_block_device_kind(name: &str) -> DiskKind {
+    match get_sys_path(name).and_then(|p| get_sys_disk_path(&p)) {
+        Some(sys_disk_path) => get_kind(&sys_disk_path),
+        None => DiskKind::Unknown(-1),
+    }
+}
+
+/// Contains block device information and I/O statistics.
+pub struct BlockDevice {
+    kind: DiskKind,
+    model: String,
+    serial: String,
+    removable: bool,
+    /// Size of the device in bytes.
+    size: u64,
+    /// Disk this device is a partition of.
+    parent: Option<String>,
+    partitions: Vec<String>,
+    /// Devices this device is built upon (like the disks of a RAID array).
+    slaves: Vec<String>,
+    /// Devices built upon this device.
+    holders: Vec<String>,
+    dm_name: Option<String>,
+    raid_status: Option<RaidStatus>,
+    /// Number of reads completed successfully.
+    reads_completed: u64,
+    old_reads_completed: u64,
+    /// Number of sectors read.
+    sectors_read: u64,
+    old_sectors_read: u64,
+    /// Number of milliseconds spent reading.
+    time_reading: u64,
+    old_time_reading: u64,
+    /// Number of writes completed successfully.
+    writes_completed: u64,
+    old_writes_completed: u64,
+    /// Number of sectors written.
+    sectors_written: u64,
+    old_sectors_written: u64,
+    /// Number of milliseconds spent writing.
+    time_writing: u64,
+    old_time_writing: u64,
+    /// Number of I/Os currently in progress.
+    ios_in_progress: u64,
+    /// Number of milliseconds spent doing I/Os.
+    time_doing_io: u64,
+    old_time_doing_io: u64,
+    /// Weighted number of milliseconds spent doing I/Os.
+    weighted_time_doing_io: u64,
+    old_weighted_time_doing_io: u64,
+    /// Number of milliseconds elapsed between the two last refreshes.
+    elapsed: u64,
+    last_refresh: Instant,
+}
+
+impl BlockDevice {
+    /// The sysfs information is only read by `update`, which is always called
+    /// after this function.
+    fn new(stats: &[u64; 11], now: Instant) -> BlockDevice {
+        BlockDevice {
+            kind: DiskKind::Unknown(-1),
+            model: String::new(),
+            serial: String::new(),
+            removable: false,
+            size: 0,
+            parent: None,
+            partitions: Vec::new(),
+            slaves: Vec::new(),
+            holders: Vec::new(),
+            dm_name: None,
+            raid_status: None,
+            reads_completed: stats[0],
+            old_reads_completed: stats[0],
+            sectors_read: stats[2],
+            old_sectors_read: stats[2],
+            time_reading: stats[3],
+            old_time_reading: stats[3],
+            writes_completed: stats[4],
+            old_writes_completed: stats[4],
+            sectors_written: stats[6],
+            old_sectors_written: stats[6],
+            time_writing: stats[7],
+            old_time_writing: stats[7],
+            ios_in_progress: stats[8],
+            time_doing_io: stats[9],
+            old_time_doing_io: stats[9],
+            weighted_time_doing_io: stats[10],
+            old_weighted_time_doing_io: stats[10],
+            elapsed: 0,
+            last_refresh: now,
+        }
+    }
+
+    /// Reads the device information from sysfs. Partitions, slaves and holders change when
+    /// the device is repartitioned or when LVM, dm or md devices are reconfigured, so it is
+    /// read again on each refresh.
+    fn update_sys_info(&mut self, name: &str) {
+        let sys_path = get_sys_path(name);
+        let sys_disk_path = sys_path.as_ref().and_then(|p| get_sys_disk_path(p));
+        let read_disk_file = |file: &str| {
+            sys_disk_path
+                .as_ref()
+                .and_then(|p| read_sys_file(&p.join(file)))
+                .unwrap_or_default()
+        };
+
+        self.kind = sys_disk_path
+            .as_ref()
+            .map(|p| get_kind(p))
+            .unwrap_or(DiskKind::Unknown(-1));
+        self.model = read_disk_file("device/model");
+        self.serial = read_disk_file("device/serial");
+        self.removable = read_disk_file("removable") == "1";
+        // The size is always given in 512 bytes sectors.
+        self.size = sys_path
+            .as_ref()
+            .and_then(|p| read_sys_file(&p.join("size")))
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(0)
+            * 512;
+        self.parent = match (&sys_path, &sys_disk_path) {
+            (Some(sys_path), Some(sys_disk_path)) if sys_path != sys_disk_path => sys_disk_path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.replace('!', "/")),
+            _ => None,
+        };
+        self.partitions = sys_path
+            .as_ref()
+            .map(|p| get_partitions(p))
+            .unwrap_or_default();
+        self.slaves = sys_path
+            .as_ref()
+            .map(|p| read_sys_dir(&p.join("slaves")))
+            .unwrap_or_default();
+        self.holders = sys_path
+            .as_ref()
+            .map(|p| read_sys_dir(&p.join("holders")))
+            .unwrap_or_default();
+        self.dm_name = sys_path
+            .as_ref()
+            .and_then(|p| read_sys_file(&p.join("dm/name")));
+    }
+
+    fn update(
+        &mut self,
+        name: &str,
+        stats: &[u64; 11],
+        raid_status: Option<RaidStatus>,
+        now: Instant,
+    ) {
+        self.update_sys_info(name);
+        // The array might have been degraded or started a resync since the last refresh.
+        self.raid_status = raid_status;
+        old_and_new!(self, reads_completed, old_reads_completed, stats[0]);
+        old_and_new!(self, sectors_read, old_sectors_read, stats[2]);
+        old_and_new!(self, time_reading, old_time_reading, stats[3]);
+        old_and_new!(self, writes_completed, old_writes_completed, stats[4]);
+        old_and_new!(self, sectors_written, old_sectors_written, stats[6]);
+        old_and_new!(self, time_writing, old_time_writing, stats[7]);
+        self.ios_in_progress = stats[8];
+        old_and_new!(self, time_doing_io, old_time_doing_io, stats[9]);
+        old_and_new!(
+            self,
+            weighted_time_doing_io,
+            old_weighted_time_doing_io,
+            stats[10]
+        );
+        let elapsed = now.duration_since(self.last_refresh);
+        self.elapsed = elapsed.as_secs() * 1_000 + u64::from(elapsed.subsec_millis());
+        self.last_refresh = now;
+    }
+}
+
+impl BlockDeviceExt for BlockDevice {
+    fn get_kind(&self) -> DiskKind {
+        self.kind
+    }
+
+    fn get_model(&self) -> &str {
+        &self.model
+    }
+
+    fn get_serial(&self) -> &str {
+        &self.serial
+    }
+
+    fn is_removable(&self) -> bool {
+        self.removable
+    }
+
+    fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    fn get_parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
+    fn get_partitions(&self) -> &[String] {
+        &self.partitions
+    }
+
+    fn get_slaves(&self) -> &[String] {
+        &self.slaves
+    }
+
+    fn get_holders(&self) -> &[String] {
+        &self.holders
+    }
+
+    fn get_dm_name(&self) -> Option<&str> {
+        self.dm_name.as_deref()
+    }
+
+    fn get_raid_status(&self) -> Option<&RaidStatus> {
+        self.raid_status.as_ref()
+    }
+
+    fn get_reads_completed(&self) -> u64 {
+        self.reads_completed
+            .saturating_sub(self.old_reads_completed)
+    }
+
+    fn get_total_reads_completed(&self) -> u64 {
+        self.reads_completed
+    }
+
+    fn get_sectors_read(&self) -> u64 {
+        self.sectors_read.saturating_sub(self.old_sectors_read)
+    }
+
+    fn get_total_sectors_read(&self) -> u64 {
+        self.sectors_read
+    }
+
+    fn get_time_reading(&self) -> u64 {
+        self.time_reading.saturating_sub(self.old_time_reading)
+    }
+
+    fn get_total_time_reading(&self) -> u64 {
+        self.time_reading
+    }
+
+    fn get_writes_completed(&self) -> u64 {
+        self.writes_completed
+            .saturating_sub(self.old_writes_completed)
+    }
+
+    fn get_total_writes_completed(&self) -> u64 {
+        self.writes_completed
+    }
+
+    fn get_sectors_written(&self) -> u64 {
+        self.sectors_written
+            .saturating_sub(self.old_sectors_written)
+    }
+
+    fn get_total_sectors_written(&self) -> u64 {
+        self.sectors_written
+    }
+
+    fn get_time_writing(&self) -> u64 {
+        self.time_writing.saturating_sub(self.old_time_writing)
+    }
+
+    fn get_total_time_writing(&self) -> u64 {
+        self.time_writing
+    }
+
+    fn get_ios_in_progress(&self) -> u64 {
+        self.ios_in_progress
+    }
+
+    fn get_time_doing_io(&self) -> u64 {
+        self.time_doing_io.saturating_sub(self.old_time_doing_io)
+    }
+
+    fn get_total_time_doing_io(&self) -> u64 {
+        self.time_doing_io
+    }
+
+    fn get_weighted_time_doing_io(&self) -> u64 {
+        self.weighted_time_doing_io
+            .saturating_sub(self.old_weighted_time_doing_io)
+    }
+
+    fn get_total_weighted_time_doing_io(&self) -> u64 {
+        self.weighted_time_doing_io
+    }
+
+    fn get_elapsed_time(&self) -> u64 {
+        self.elapsed
+    }
+}
+
+fn get_raid_statuses() -> HashMap<String, RaidStatus> {
+    match get_all_data("/proc/mdstat", 16_385) {
+        Ok(content) => parse_mdstat(&content),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// Parses the content of `/proc/mdstat`, which looks like this:
+///
+/// ```text
+/// Personalities : [raid1]
+/// md0 : active raid1 sdb1[1] sda1[0](F)
+///       1046528 blocks super 1.2 [2/1] [_U]
+///       [=>...................]  recovery =  8.5% (89408/1046528) finish=0.1min speed=89408K/sec
+///
+/// unused devices: <none>
+/// ```
+fn parse_mdstat(content: &str) -> HashMap<String, RaidStatus> {
+    let mut statuses = HashMap::new();
+    let mut current: Option<(String, RaidStatus)> = None;
+
+    for line in content.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            if let Some((name, status)) = current.take() {
+                statuses.insert(name, status);
+            }
+            let mut halves = line.splitn(2, " : ");
+            let name = match halves.next() {
+                Some(name) if name.starts_with("md") => name.trim(),
+                _ => continue,
+            };
+            let mut status = RaidStatus {
+                level: String::new(),
+                active: false,
+                devices: Vec::new(),
+                failed_devices: Vec::new(),
+                expected_devices: 0,
+                working_devices: 0,
+                sync_action: None,
+                sync_progress: None,
+            };
+            for (pos, field) in halves.next().unwrap_or("").split_whitespace().enumerate() {
+                if pos == 0 {
+                    status.active = field == "active";
+                } else if field.starts_with('(') {
+                    // Something like `(auto-read-only)`.
+                } else if let Some(index) = field.find('[') {
+                    let device = field[..index].to_owned();
+                    if field.ends_with("(F)") {
+                        status.failed_devices.push(device.clone());
+                    }
+                    status.devices.push(device);
+                } else {
+                    status.level = field.to_owned();
+                }
+            }
+            current = Some((name.to_owned(), status));
+            continue;
+        }
+        let status = match current {
+            Some((_, ref mut status)) => status,
+            None => continue,
+        };
+        for field in line.split_whitespace() {
+            // The number of expected and working devices: `[2/1]`.
+            if field.starts_with('[') && field.ends_with(']') && field.contains('/') {
+                let mut parts = field[1..field.len() - 1].splitn(2, '/');
+                status.expected_devices = parts.next().and_then(|x| x.parse().ok()).unwrap_or(0);
+                status.working_devices = parts.next().and_then(|x| x.parse().ok()).unwrap_or(0);
+            }
+        }
+        for action in &["resync", "recovery", "check", "reshape", "repair"] {
+            if let Some(pos) = line.find(action) {
+                let rest = line[pos + action.len()..].trim_start();
+                if !rest.starts_with('=') {
+                    continue;
+                }
+                status.sync_action = Some((*action).to_owned());
+                // It can also be `resync=DELAYED` or `resync=PENDING`.
+                status.sync_progress = rest[1..]
+                    .split_whitespace()
+                    .next()
+                    .and_then(|x| x.trim_end_matches('%').parse().ok());
+                break;
+            }
+        }
+    }
+    if let Some((name, status)) = current {
+        statuses.insert(name, status);
+    }
+    statuses
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_parse_mdstat_degraded() {
+        let statuses = parse_mdstat(
+            "Personalities : [raid1]
+md0 : active raid1 sdb1[1] sda1[0](F)
+      1046528 blocks super 1.2 [2/1] [_U]
+
+unused devices: <none>
+",
+        );
+        assert_eq!(statuses.len(), 1);
+        let md0 = &statuses["md0"];
+        assert_eq!(md0.level, "raid1");
+        assert!(md0.active);
+        assert_eq!(md0.devices, vec!["sdb1", "sda1"]);
+        assert_eq!(md0.failed_devices, vec!["sda1"]);
+        assert_eq!(md0.expected_devices, 2);
+        assert_eq!(md0.working_devices, 1);
+        assert_eq!(md0.sync_action, None);
+        assert_eq!(md0.sync_progress, None);
+        assert!(md0.is_degraded());
+    }
+
+    #[test]
+    fn check_parse_mdstat_sync() {
+        let statuses = parse_mdstat(
+            "Personalities : [raid1]
+md0 : active raid1 sdc1[2] sda1[0]
+      1046528 blocks super 1.2 [2/1] [U_]
+      [==>..................]  recovery = 12.3% (128768/1046528) finish=0.7min speed=21461K/sec
+
+md1 : active raid1 sdd1[1] sdb1[0]
+      2094080 blocks super 1.2 [2/2] [UU]
+      \tresync=DELAYED
+
+unused devices: <none>
+",
+        );
+        assert_eq!(statuses.len(), 2);
+        let md0 = &statuses["md0"];
+        assert!(md0.is_degraded());
+        assert_eq!(md0.sync_action.as_deref(), Some("recovery"));
+        assert_eq!(md0.sync_progress, Some(12.3));
+        let md1 = &statuses["md1"];
+        assert!(!md1.is_degraded());
+        assert_eq!(md1.sync_action.as_deref(), Some("resync"));
+        assert_eq!(md1.sync_progress, None);
+    }
+
+    #[test]
+    fn check_parse_mdstat_inactive_and_read_only() {
+        let statuses = parse_mdstat(
+            "Personalities : [raid1]
+md127 : inactive sdb[1](S) sda[0](S)
+      3906764976 blocks super 1.2
+
+md126 : active (auto-read-only) raid1 sdd[1] sdc[0]
+      976631488 blocks super 1.2 [2/2] [UU]
+        resync=PENDING
+      bitmap: 0/8 pages [0KB], 65536KB chunk
+
+unused devices: <none>
+",
+        );
+        assert_eq!(statuses.len(), 2);
+        let md127 = &statuses["md127"];
+        assert!(!md127.active);
+        assert_eq!(md127.level, "");
+        assert_eq!(md127.devices, vec!["sdb", "sda"]);
+        assert!(md127.failed_devices.is_empty());
+        assert!(!md127.is_degraded());
+        let md126 = &statuses["md126"];
+        assert!(md126.active);
+        assert_eq!(md126.level, "raid1");
+        assert_eq!(md126.devices, vec!["sdd", "sdc"]);
+        assert_eq!(md126.expected_devices, 2);
+        assert_eq!(md126.working_devices, 2);
+        assert_eq!(md126.sync_action.as_deref(), Some("resync"));
+        assert_eq!(md126.sync_progress, None);
+    }
+
+    #[test]
+    fn check_parse_mdstat_multi_line() {
+        let statuses = parse_mdstat(
+            "Personalities : [raid10] [raid6] [raid5] [raid4]
+md2 : active raid10 sdd1[3] sdc1[2] sdb1[1] sda1[0]
+      2093056 blocks super 1.2 512K chunks 2 near-copies [4/4] [UUUU]
+      bitmap: 0/1 pages [0KB], 65536KB chunk
+
+md1 : active raid5 sdh1[4] sdg1[2] sdf1[1] sde1[0]
+      3139584 blocks super 1.2 level 5, 512k chunk, algorithm 2 [4/3] [UUU_]
+      [===>.................]  recovery = 15.7% (164736/1046528) finish=0.7min speed=20592K/sec
+      bitmap: 1/1 pages [4KB], 65536KB chunk
+
+unused devices: <none>
+",
+        );
+        assert_eq!(statuses.len(), 2);
+        let md2 = &statuses["md2"];
+        assert_eq!(md2.level, "raid10");
+        assert_eq!(md2.devices.len(), 4);
+        assert_eq!(md2.expected_devices, 4);
+        assert_eq!(md2.working_devices, 4);
+        assert_eq!(md2.sync_action, None);
+        assert!(!md2.is_degraded());
+        let md1 = &statuses["md1"];
+        assert_eq!(md1.level, "raid5");
+        assert_eq!(md1.devices, vec!["sdh1", "sdg1", "sdf1", "sde1"]);
+        assert_eq!(md1.expected_devices, 4);
+        assert_eq!(md1.working_devices, 3);
+        assert_eq!(md1.sync_action.as_deref(), Some("recovery"));
+        assert_eq!(md1.sync_progress, Some(15.7));
+        assert!(md1.is_degraded());
+    }
+
+    #[test]
+    fn check_parse_mdstat_empty() {
+        assert!(parse_mdstat("Personalities : \nunused devices: <none>\n").is_empty());
+        assert!(parse_mdstat("").is_empty());
+    }
+}
@@ -0,0 +1,382 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2017 Guillaume Gomez
+//
+
+use utils::to_cpath;
+use DiskExt;
+use DiskKind;
+
+use libc::{statvfs, ST_RDONLY};
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::mem;
+use std::os::unix::ffi::OsStringExt;
+use std::path::{Path, PathBuf};
+
+use super::block_device::get_block_device_kind;
+use super::system::get_all_data;
+
+/// Struct containing a disk information.
+pub struct Disk {
+    kind: DiskKind,
+    name: OsString,
+    file_system: Vec<u8>,
+    mount_point: PathBuf,
+    total_space: u64,
+    available_space: u64,
+    total_inodes: u64,
+    free_inodes: u64,
+    mount_options: Vec<String>,
+    device_id: (u32, u32),
+}
+
+impl DiskExt for Disk {
+    fn get_kind(&self) -> DiskKind {
+        self.kind
+    }
+
+    fn get_name(&self) -> &OsStr {
+        &self.name
+    }
+
+    fn get_file_system(&self) -> &[u8] {
+        &self.file_system
+    }
+
+    fn get_mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+
+    fn get_total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    fn get_available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    fn get_total_inodes(&self) -> u64 {
+        self.total_inodes
+    }
+
+    fn get_free_inodes(&self) -> u64 {
+        self.free_inodes
+    }
+
+    fn get_mount_options(&self) -> &[String] {
+        &self.mount_options
+    }
+
+    fn get_device_id(&self) -> (u32, u32) {
+        self.device_id
+    }
+
+    fn refresh(&mut self) -> bool {
+        match get_usage(&self.mount_point) {
+            Some(usage) => {
+                // The file system might have been resized.
+                self.total_space = usage.total_space;
+                self.available_space = usage.available_space;
+                self.total_inodes = usage.total_inodes;
+                self.free_inodes = usage.free_inodes;
+                // The file system might have been remounted (read-only after errors for example).
+                set_read_only(&mut self.mount_options, usage.read_only);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+struct Usage {
+    total_space: u64,
+    available_space: u64,
+    total_inodes: u64,
+    free_inodes: u64,
+    read_only: bool,
+}
+
+/// Returns the space (in bytes) and inodes usage of the given mount point.
+// The `statvfs` fields don't have the same size on all targets.
+#[allow(clippy::useless_conversion)]
+fn get_usage(mount_point: &Path) -> Option<Usage> {
+    let mount_point_cpath = to_cpath(mount_point);
+    unsafe {
+        let mut stat: statvfs = mem::zeroed();
+        if statvfs(mount_point_cpath.as_ptr() as *const _, &mut stat) == 0 {
+            let block_size = u64::from(stat.f_frsize);
+            Some(Usage {
+                total_space: block_size * u64::from(stat.f_blocks),
+                available_space: block_size * u64::from(stat.f_bavail),
+                total_inodes: u64::from(stat.f_files),
+                free_inodes: u64::from(stat.f_ffree),
+                read_only: stat.f_flag & ST_RDONLY != 0,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn new_disk(
+    name: OsString,
+    mount_point: PathBuf,
+    file_system: &[u8],
+    mount_options: Vec<String>,
+    device_id: (u32, u32),
+) -> Option<Disk> {
+    let usage = get_usage(&mount_point)?;
+    if usage.total_space == 0 {
+        return None;
+    }
+    Some(Disk {
+        kind: find_kind_for_disk(&name),
+        name,
+        file_system: file_system.to_owned(),
+        mount_point,
+        total_space: usage.total_space,
+        available_space: usage.available_space,
+        total_inodes: usage.total_inodes,
+        free_inodes: usage.free_inodes,
+        mount_options,
+        device_id,
+    })
+}
+
+/// Merges the per-mount options with the super block ones (`rw,nosuid` and `rw,errors=remount-ro`
+/// for example), without duplicates.
+///
+/// If one of the two is read-only, the mount point is read-only as well so `rw` is dropped.
+fn parse_mount_options(mount_options: &str, super_options: &str) -> Vec<String> {
+    let mut options: Vec<String> = Vec::new();
+
+    for option in mount_options.split(',').chain(super_options.split(',')) {
+        if option.is_empty() || options.iter().any(|o| o == option) {
+            continue;
+        }
+        options.push(option.to_owned());
+    }
+    if options.iter().any(|o| o == "ro") {
+        options.retain(|o| o != "rw");
+    }
+    options
+}
+
+/// Replaces `rw` with `ro` (or the other way around) in the mount options if needed.
+fn set_read_only(options: &mut Vec<String>, read_only: bool) {
+    let (old, new) = if read_only {
+        ("rw", "ro")
+    } else {
+        ("ro", "rw")
+    };
+    if options.iter().any(|o| o == new) {
+        options.retain(|o| o != old);
+    } else if let Some(option) = options.iter_mut().find(|o| *o == old) {
+        *option = new.to_owned();
+    } else {
+        options.insert(0, new.to_owned());
+    }
+}
+
+fn parse_device_id(device_id: &str) -> (u32, u32) {
+    let mut parts = device_id.splitn(2, ':');
+    let major = parts.next().and_then(|x| x.parse().ok()).unwrap_or(0);
+    let minor = parts.next().and_then(|x| x.parse().ok()).unwrap_or(0);
+    (major, minor)
+}
+
+fn find_kind_for_disk(name: &OsStr) -> DiskKind {
+    // `name` can be a symlink (like `/dev/mapper/root` or `/dev/disk/by-uuid/...`), so we need
+    // to resolve it to get the actual block device name.
+    let path = Path::new(name);
+    if !path.starts_with("/dev") {
+        return DiskKind::Unknown(-1);
+    }
+    match fs::canonicalize(path)
+        .ok()
+        .as_ref()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+    {
+        Some(device_name) => get_block_device_kind(device_name),
+        None => DiskKind::Unknown(-1),
+    }
+}
+
+/// The kernel escapes spaces, tabs, newlines and backslashes in `/proc/self/mountinfo` paths
+/// with their octal representation (`\040` for a space for example).
+fn unescape_mountinfo_field(field: &str) -> OsString {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let value = bytes
+            .get(pos + 1..pos + 4)
+            .filter(|digits| {
+                bytes[pos] == b'\\' && digits.iter().all(|c| (b'0'..=b'7').contains(c))
+            })
+            .map(|digits| {
+                digits
+                    .iter()
+                    .fold(0u32, |value, c| value * 8 + u32::from(c - b'0'))
+            });
+        // Values greater than 0xFF (like `\777`) aren't valid escapes, they're kept as is.
+        if let Some(value) = value.filter(|value| *value <= 0xFF) {
+            out.push(value as u8);
+            pos += 4;
+        } else {
+            out.push(bytes[pos]);
+            pos += 1;
+        }
+    }
+    OsString::from_vec(out)
+}
+
+fn is_ignored_file_system(fs_vfstype: &str) -> bool {
+    match fs_vfstype {
+        "sysfs" | // pseudo file system for kernel objects
+        "proc" |  // another pseudo file system
+        "tmpfs" |
+        "devtmpfs" |
+        "devpts" |
+        "mqueue" |
+        "hugetlbfs" |
+        "debugfs" |
+        "tracefs" |
+        "securityfs" |
+        "configfs" |
+        "fusectl" |
+        "bpf" |
+        "autofs" |
+        "binfmt_misc" |
+        "cgroup" |
+        "cgroup2" |
+        "pstore" | // https://www.kernel.org/doc/Documentation/ABI/testing/pstore
+        "squashfs" | // squashfs is a compressed read-only file system (for snaps)
+        "rpc_pipefs" | // The pipefs pseudo file system service
+        "iso9660" => true, // optical media
+        _ => false,
+    }
+}
+
+pub fn get_all_disks() -> Vec<Disk> {
+    // The format of this file is described in `man 5 proc`:
+    //
+    // 36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue
+    //
+    // Only the fields before the " - " separator have a fixed position, the number of optional
+    // fields varies.
+    let content = get_all_data("/proc/self/mountinfo", 16_385).unwrap_or_default();
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut halves = line.splitn(2, " - ");
+            let mut fields = halves.next()?.split(' ');
+            let mut extra_fields = halves.next()?.split(' ');
+
+            let device_id = fields.nth(2)?;
+            let fs_file = fields.nth(1)?;
+            let fs_mntops = fields.next()?;
+            let fs_vfstype = extra_fields.next()?;
+            let fs_spec = extra_fields.next()?;
+            let fs_super_options = extra_fields.next().unwrap_or("");
+
+            if is_ignored_file_system(fs_vfstype)
+                || fs_file.starts_with("/sys")
+                || fs_file.starts_with("/proc")
+                || (fs_file.starts_with("/run") && !fs_file.starts_with("/run/media"))
+                || fs_spec.starts_with("sunrpc")
+            {
+                return None;
+            }
+            new_disk(
+                unescape_mountinfo_field(fs_spec),
+                PathBuf::from(unescape_mountinfo_field(fs_file)),
+                fs_vfstype.as_bytes(),
+                parse_mount_options(fs_mntops, fs_super_options),
+                parse_device_id(device_id),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::os::unix::ffi::OsStrExt;
+
+    #[test]
+    fn check_parse_mount_options() {
+        assert_eq!(
+            parse_mount_options("rw,nosuid,relatime", "rw,errors=remount-ro"),
+            vec!["rw", "nosuid", "relatime", "errors=remount-ro"],
+        );
+        // Read-only if either the mount point or the super block is.
+        assert_eq!(
+            parse_mount_options("ro,noatime", "rw"),
+            vec!["ro", "noatime"]
+        );
+        assert_eq!(
+            parse_mount_options("rw,noatime", "ro"),
+            vec!["noatime", "ro"]
+        );
+        assert_eq!(parse_mount_options("rw", ""), vec!["rw"]);
+        assert!(parse_mount_options("", "").is_empty());
+    }
+
+    #[test]
+    fn check_set_read_only() {
+        let mut options = vec!["rw".to_owned(), "noatime".to_owned()];
+        set_read_only(&mut options, true);
+        assert_eq!(options, vec!["ro", "noatime"]);
+        set_read_only(&mut options, true);
+        assert_eq!(options, vec!["ro", "noatime"]);
+        set_read_only(&mut options, false);
+        assert_eq!(options, vec!["rw", "noatime"]);
+
+        let mut options = vec!["noatime".to_owned()];
+        set_read_only(&mut options, true);
+        assert_eq!(options, vec!["ro", "noatime"]);
+    }
+
+    #[test]
+    fn check_parse_device_id() {
+        assert_eq!(parse_device_id("8:1"), (8, 1));
+        assert_eq!(parse_device_id("259:12"), (259, 12));
+        assert_eq!(parse_device_id("8"), (8, 0));
+        assert_eq!(parse_device_id("a:b"), (0, 0));
+        assert_eq!(parse_device_id(""), (0, 0));
+    }
+
+    #[test]
+    fn check_unescape_mountinfo_field() {
+        assert_eq!(
+            unescape_mountinfo_field("/mnt/data"),
+            OsStr::new("/mnt/data")
+        );
+        assert_eq!(
+            unescape_mountinfo_field("/mnt/my\\040disk"),
+            OsStr::new("/mnt/my disk"),
+        );
+        assert_eq!(
+            unescape_mountinfo_field("/a\\011b\\012c\\134d"),
+            OsStr::new("/a\tb\nc\\d"),
+        );
+        // Escape at the end of the field.
+        assert_eq!(unescape_mountinfo_field("/mnt\\040"), OsStr::new("/mnt "));
+        // Not an octal escape.
+        assert_eq!(unescape_mountinfo_field("/a\\9b"), OsStr::new("/a\\9b"));
+        assert_eq!(unescape_mountinfo_field("/a\\04"), OsStr::new("/a\\04"));
+        // Out of range.
+        assert_eq!(unescape_mountinfo_field("/a\\777b"), OsStr::new("/a\\777b"));
+        assert_eq!(unescape_mountinfo_field("/a\\400"), OsStr::new("/a\\400"));
+        assert_eq!(
+            unescape_mountinfo_field("/a\\377"),
+            OsStr::from_bytes(b"/a\xff")
+        );
+    }
+}
@@ -4,15 +4,36 @@
 // Copyright (c) 2015 Guillaume Gomez
 //
 
+macro_rules! old_and_new {
+    ($ty_:expr, $name:ident, $old:ident) => {{
+        $ty_.$old = $ty_.$name;
+        $ty_.$name = $name;
+    }};
+    ($ty_:expr, $name:ident, $old:ident, $path:expr) => {{
+        let _tmp = $path;
+        $ty_.$old = $ty_.$name;
+        $ty_.$name = _tmp;
+    }};
+}
+
+pub mod block_device;
 pub mod component;
+pub mod disk;
 pub mod network;
 pub mod process;
+pub mod process_handle;
 pub mod processor;
+pub mod socket;
 pub mod system;
+pub mod thread;
 pub mod users;
 
+pub use self::block_device::{BlockDevice, BlockDevices};
 pub use self::component::Component;
+pub use self::disk::Disk;
 pub use self::network::{NetworkData, Networks};
 pub use self::process::{Process, ProcessStatus};
+pub use self::process_handle::ProcessHandle;
 pub use self::processor::Processor;
 pub use self::system::System;
+pub use self::thread::Thread;
@@ -5,17 +5,52 @@
 //
 
 use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fmt;
-use std::fs::File;
+use std::fs::{self, File};
+use std::io;
+use std::mem;
+use std::os::unix::fs::FileTypeExt;
 use std::path::{Path, PathBuf};
+use std::ptr::{null, null_mut};
+use std::time::Duration;
 
-use libc::{c_int, gid_t, kill, uid_t};
+use libc::{
+    c_int, cpu_set_t, gid_t, kill, prlimit, rlim_t, rlimit, sched_getaffinity, sched_param,
+    sched_setaffinity, sched_setscheduler, setpriority, sysconf, uid_t, _SC_CLK_TCK, CPU_ISSET,
+    CPU_SET, EINVAL, PRIO_PROCESS, RLIM_INFINITY, SCHED_BATCH, SCHED_FIFO, SCHED_IDLE, SCHED_OTHER,
+    SCHED_RR,
+};
 
+use super::process_handle::ProcessHandle;
+use super::system::{get_all_data, parse_stat_file};
+use super::thread::Thread;
+
+use utils::get_kill_error;
+use Cgroup;
+use CpuTime;
+use DiskUsage;
+use FileKind;
+use Gid;
+use KillError;
+use Limit;
+use MemoryBreakdown;
+use MemoryDetails;
+use MemoryMap;
+use Namespace;
+use NamespaceKind;
+use OpenFile;
 use Pid;
+use ProcessActivity;
 use ProcessExt;
+use ProcessId;
+use Resource;
+use ResourceLimit;
+use SchedulingPolicy;
+use Uid;
 
 /// Enum describing the different status of a process.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ProcessStatus {
     /// Waiting in uninterruptible disk sleep.
     Idle,
@@ -105,6 +140,8 @@ pub struct Process {
     pub(crate) pid: Pid,
     parent: Option<Pid>,
     pub(crate) environ: Vec<String>,
+    pub(crate) cmd_os: Vec<OsString>,
+    pub(crate) environ_os: Vec<OsString>,
     pub(crate) cwd: PathBuf,
     pub(crate) root: PathBuf,
     pub(crate) memory: u64,
@@ -114,15 +151,27 @@ pub struct Process {
     old_utime: u64,
     old_stime: u64,
     start_time: u64,
+    pub(crate) start_ticks: u64,
     updated: bool,
     cpu_usage: f32,
     /// User id of the process owner.
     pub uid: uid_t,
     /// Group id of the process owner.
     pub gid: gid_t,
+    pub(crate) real_uid: uid_t,
+    pub(crate) real_gid: gid_t,
     pub(crate) status: ProcessStatus,
-    /// Tasks run by this process.
-    pub tasks: HashMap<Pid, Process>,
+    /// Only used by the list of all processes, the tasks of a process are available through
+    /// [`ProcessExt::threads`][crate::ProcessExt::threads].
+    pub(crate) tasks: HashMap<Pid, Process>,
+    pub(crate) threads: HashMap<Pid, Thread>,
+    memory_breakdown: MemoryBreakdown,
+    thread_count: u64,
+    activity: ProcessActivity,
+    pub(crate) priority: i64,
+    pub(crate) nice: i32,
+    pub(crate) realtime_priority: u32,
+    pub(crate) scheduling_policy: u32,
     pub(crate) stat_file: Option<File>,
     old_read_bytes: u64,
     old_written_bytes: u64,
@@ -138,6 +187,8 @@ impl ProcessExt for Process {
             parent,
             cmd: Vec::with_capacity(2),
             environ: Vec::with_capacity(10),
+            cmd_os: Vec::new(),
+            environ_os: Vec::new(),
             exe: PathBuf::new(),
             cwd: PathBuf::new(),
             root: PathBuf::new(),
@@ -150,14 +201,25 @@ impl ProcessExt for Process {
             old_stime: 0,
             updated: true,
             start_time,
+            start_ticks: 0,
             uid: 0,
             gid: 0,
+            real_uid: 0,
+            real_gid: 0,
             status: ProcessStatus::Unknown(0),
             tasks: if pid == 0 {
                 HashMap::with_capacity(1000)
             } else {
                 HashMap::new()
             },
+            threads: HashMap::new(),
+            memory_breakdown: MemoryBreakdown::default(),
+            thread_count: 0,
+            activity: ProcessActivity::default(),
+            priority: 0,
+            nice: 0,
+            realtime_priority: 0,
+            scheduling_policy: 0,
             stat_file: None,
             old_read_bytes: 0,
             old_written_bytes: 0,
@@ -166,8 +228,16 @@ impl ProcessExt for Process {
         }
     }
 
-    fn kill(&self, signal: ::Signal) -> bool {
-        unsafe { kill(self.pid, signal as c_int) == 0 }
+    fn try_kill(&self, signal: ::Signal) -> Result<(), KillError> {
+        if unsafe { kill(self.pid, signal as c_int) } == 0 {
+            Ok(())
+        } else {
+            Err(get_kill_error(io::Error::last_os_error()))
+        }
+    }
+
+    fn handle(&self) -> io::Result<ProcessHandle> {
+        ProcessHandle::new(self.pid, self.start_ticks)
     }
 
     fn name(&self) -> &str {
@@ -190,6 +260,14 @@ impl ProcessExt for Process {
         &self.environ
     }
 
+    fn cmd_os(&self) -> Option<&[OsString]> {
+        Some(&self.cmd_os)
+    }
+
+    fn environ_os(&self) -> Option<&[OsString]> {
+        Some(&self.environ_os)
+    }
+
     fn cwd(&self) -> &Path {
         self.cwd.as_path()
     }
@@ -220,10 +298,202 @@ impl ProcessExt for Process {
         self.start_time
     }
 
+    fn process_id(&self) -> ProcessId {
+        ProcessId {
+            pid: self.pid,
+            start_time: self.start_ticks,
+            boot_id: *BOOT_ID,
+        }
+    }
+
     fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
 
+    fn disk_usage(&self) -> DiskUsage {
+        DiskUsage {
+            written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
+            total_written_bytes: self.written_bytes,
+            read_bytes: self.read_bytes.saturating_sub(self.old_read_bytes),
+            total_read_bytes: self.read_bytes,
+        }
+    }
+
+    fn cpu_time(&self) -> Option<CpuTime> {
+        let clock_cycle = unsafe { sysconf(_SC_CLK_TCK) } as u64;
+        Some(CpuTime {
+            total_user_time: ticks_to_duration(self.utime, clock_cycle),
+            user_time: ticks_to_duration(self.utime.saturating_sub(self.old_utime), clock_cycle),
+            total_system_time: ticks_to_duration(self.stime, clock_cycle),
+            system_time: ticks_to_duration(self.stime.saturating_sub(self.old_stime), clock_cycle),
+        })
+    }
+
+    fn memory_breakdown(&self) -> Option<MemoryBreakdown> {
+        Some(self.memory_breakdown)
+    }
+
+    fn thread_count(&self) -> Option<u64> {
+        Some(self.thread_count)
+    }
+
+    fn activity(&self) -> Option<ProcessActivity> {
+        Some(self.activity)
+    }
+
+    fn nice(&self) -> Option<i32> {
+        Some(self.nice)
+    }
+
+    fn priority(&self) -> Option<i64> {
+        Some(self.priority)
+    }
+
+    fn scheduling_policy(&self) -> Option<SchedulingPolicy> {
+        Some(SchedulingPolicy::from(self.scheduling_policy))
+    }
+
+    fn realtime_priority(&self) -> Option<u32> {
+        Some(self.realtime_priority)
+    }
+
+    fn set_nice(&self, nice: i32) -> io::Result<()> {
+        if unsafe { setpriority(PRIO_PROCESS as _, self.pid as _, nice) } == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    fn set_scheduling_policy(&self, policy: SchedulingPolicy, priority: u32) -> io::Result<()> {
+        let policy = match policy {
+            SchedulingPolicy::Other => SCHED_OTHER,
+            SchedulingPolicy::Batch => SCHED_BATCH,
+            SchedulingPolicy::Idle => SCHED_IDLE,
+            SchedulingPolicy::Fifo => SCHED_FIFO,
+            SchedulingPolicy::RoundRobin => SCHED_RR,
+            // `SCHED_DEADLINE` can only be set with `sched_setattr`.
+            SchedulingPolicy::Deadline | SchedulingPolicy::Unknown(_) => {
+                return Err(io::Error::from_raw_os_error(EINVAL));
+            }
+        };
+        let param = sched_param {
+            sched_priority: priority as c_int,
+        };
+        if unsafe { sched_setscheduler(self.pid, policy, &param) } == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    fn cpu_affinity(&self) -> Option<Vec<usize>> {
+        get_cpu_affinity(self.pid)
+    }
+
+    fn set_cpu_affinity(&self, processors: &[usize]) -> io::Result<()> {
+        set_cpu_affinity(self.pid, processors)
+    }
+
+    fn user_id(&self) -> Option<Uid> {
+        Some(self.uid)
+    }
+
+    fn group_id(&self) -> Option<Gid> {
+        Some(self.gid)
+    }
+
+    fn real_user_id(&self) -> Option<Uid> {
+        Some(self.real_uid)
+    }
+
+    fn real_group_id(&self) -> Option<Gid> {
+        Some(self.real_gid)
+    }
+
+    fn threads(&self) -> &HashMap<Pid, Thread> {
+        &self.threads
+    }
+
+    fn open_files(&self) -> Option<Vec<OpenFile>> {
+        get_open_files(&Path::new("/proc").join(self.pid.to_string()))
+    }
+
+    fn open_files_count(&self) -> Option<usize> {
+        fs::read_dir(Path::new("/proc").join(self.pid.to_string()).join("fd"))
+            .ok()
+            .map(|entries| entries.count())
+    }
+
+    fn memory_details(&self) -> Option<MemoryDetails> {
+        let path = Path::new("/proc").join(self.pid.to_string());
+        // `smaps_rollup` is only available since Linux 4.14 and is much faster to read than
+        // `smaps` so we only fallback to `smaps` if needed.
+        get_all_data(path.join("smaps_rollup"), 2048)
+            .or_else(|_| get_all_data(path.join("smaps"), 16_384))
+            .ok()
+            .map(|data| parse_smaps(&data).0)
+    }
+
+    fn memory_maps(&self) -> Option<Vec<MemoryMap>> {
+        let path = Path::new("/proc").join(self.pid.to_string()).join("smaps");
+        get_all_data(path, 16_384)
+            .ok()
+            .map(|data| parse_smaps(&data).1)
+    }
+
+    fn resource_limits(&self) -> Option<Vec<ResourceLimit>> {
+        let path = Path::new("/proc").join(self.pid.to_string()).join("limits");
+        get_all_data(path, 2048)
+            .ok()
+            .map(|data| parse_limits(&data))
+    }
+
+    fn set_resource_limit(
+        &self,
+        resource: Resource,
+        soft: Option<Limit>,
+        hard: Option<Limit>,
+    ) -> io::Result<()> {
+        let resource = get_rlimit_resource(resource) as _;
+        let mut limit = rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        if (soft.is_none() || hard.is_none())
+            && unsafe { prlimit(self.pid, resource, null(), &mut limit) } != 0
+        {
+            // We need the current limits to keep the ones which aren't set.
+            return Err(io::Error::last_os_error());
+        }
+        let to_rlim = |limit: Limit| match limit {
+            Limit::Value(x) => x as rlim_t,
+            Limit::Unlimited => RLIM_INFINITY,
+        };
+        if let Some(soft) = soft {
+            limit.rlim_cur = to_rlim(soft);
+        }
+        if let Some(hard) = hard {
+            limit.rlim_max = to_rlim(hard);
+        }
+        let ret = unsafe { prlimit(self.pid, resource, &limit, null_mut()) };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    fn cgroups(&self) -> Option<Vec<Cgroup>> {
+        let path = Path::new("/proc").join(self.pid.to_string()).join("cgroup");
+        get_all_data(path, 1024)
+            .ok()
+            .map(|data| parse_cgroups(&data))
+    }
+
+    fn namespaces(&self) -> Option<Vec<Namespace>> {
+        get_namespaces(&Path::new("/proc").join(self.pid.to_string()))
+    }
 }
 
 impl Drop for Process {
@@ -236,10 +506,20 @@ impl Drop for Process {
     }
 }
 
+/// Converts a number of clock ticks into a `Duration`.
+fn ticks_to_duration(ticks: u64, clock_cycle: u64) -> Duration {
+    let clock_cycle = clock_cycle.max(1);
+    Duration::from_secs(ticks / clock_cycle)
+        + Duration::from_nanos(ticks % clock_cycle * 1_000_000_000 / clock_cycle)
+}
+
 pub fn compute_cpu_usage(p: &mut Process, nb_processors: u64, total_time: f32) {
     p.cpu_usage =
         ((p.utime - p.old_utime + p.stime - p.old_stime) * nb_processors * 100) as f32 / total_time;
     p.updated = false;
+    for thread in p.threads.values_mut() {
+        thread.compute_cpu_usage(nb_processors, total_time);
+    }
 }
 
 pub fn set_time(p: &mut Process, utime: u64, stime: u64) {
@@ -253,3 +533,553 @@ pub fn set_time(p: &mut Process, utime: u64, stime: u64) {
 pub fn has_been_updated(p: &Process) -> bool {
     p.updated
 }
+
+/// Id of the current boot, read from `/proc/sys/kernel/random/boot_id`.
+static BOOT_ID: once_cell::sync::Lazy<u128> = once_cell::sync::Lazy::new(|| {
+    get_all_data("/proc/sys/kernel/random/boot_id", 64)
+        .ok()
+        .and_then(|id| u128::from_str_radix(&id.trim().replace('-', ""), 16).ok())
+        .unwrap_or(0)
+});
+
+/// Sets the new `value` of a counter and computes the difference with the previous one.
+fn update_counter(total: &mut u64, delta: &mut u64, value: u64) {
+    *delta = value.saturating_sub(*total);
+    *total = value;
+}
+
+/// Updates the page faults and the children times of the process from the fields of
+/// `/proc/[pid]/stat`.
+pub(crate) fn update_activity(p: &mut Process, parts: &[&str], clock_cycle: u64) {
+    let get = |index: usize| {
+        parts
+            .get(index)
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(0u64)
+    };
+    let to_ms = |ticks: u64| ticks * 1_000 / clock_cycle.max(1);
+    let activity = &mut p.activity;
+    update_counter(
+        &mut activity.total_minor_faults,
+        &mut activity.minor_faults,
+        get(9),
+    );
+    update_counter(
+        &mut activity.total_major_faults,
+        &mut activity.major_faults,
+        get(11),
+    );
+    update_counter(
+        &mut activity.total_children_user_time,
+        &mut activity.children_user_time,
+        to_ms(get(15)),
+    );
+    update_counter(
+        &mut activity.total_children_system_time,
+        &mut activity.children_system_time,
+        to_ms(get(16)),
+    );
+}
+
+/// Updates the memory breakdown, the number of threads and the context switches of the process
+/// from the content of `/proc/[pid]/status`.
+pub(crate) fn update_from_status_data(p: &mut Process, status_data: &str) {
+    // Kernel threads and zombies don't have memory lines, so previous values mustn't be kept.
+    p.memory_breakdown = MemoryBreakdown::default();
+    for line in status_data.lines() {
+        let mut parts = line.splitn(2, ':');
+        let key = parts.next().unwrap_or("");
+        let value = match parts.next() {
+            Some(value) => value
+                .trim()
+                .trim_end_matches(" kB")
+                .parse::<u64>()
+                .unwrap_or(0),
+            None => continue,
+        };
+        let breakdown = &mut p.memory_breakdown;
+        match key {
+            "VmPeak" => breakdown.peak_virtual_memory = value,
+            "VmLck" => breakdown.locked = value,
+            "VmHWM" => breakdown.peak_memory = value,
+            "RssAnon" => breakdown.rss_anon = value,
+            "RssFile" => breakdown.rss_file = value,
+            "RssShmem" => breakdown.rss_shmem = value,
+            "VmPTE" => breakdown.page_tables = value,
+            "VmSwap" => breakdown.swap = value,
+            "Threads" => p.thread_count = value,
+            "voluntary_ctxt_switches" => update_counter(
+                &mut p.activity.total_voluntary_context_switches,
+                &mut p.activity.voluntary_context_switches,
+                value,
+            ),
+            "nonvoluntary_ctxt_switches" => {
+                update_counter(
+                    &mut p.activity.total_involuntary_context_switches,
+                    &mut p.activity.involuntary_context_switches,
+                    value,
+                );
+                // This is the last line we're interested into.
+                break;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Updates the threads of the process from `/proc/[pid]/task` and removes the ones which exited.
+pub(crate) fn update_threads(p: &mut Process, path: &Path) {
+    let entries = match fs::read_dir(path.join("task")) {
+        Ok(entries) => entries,
+        Err(_) => {
+            p.threads.clear();
+            return;
+        }
+    };
+    let mut updated_tids = Vec::with_capacity(p.threads.len());
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let tid = match entry
+            .file_name()
+            .to_str()
+            .and_then(|x| x.parse::<Pid>().ok())
+        {
+            Some(tid) => tid,
+            None => continue,
+        };
+        let task_path = entry.path();
+        let data = match get_all_data(task_path.join("stat"), 1024) {
+            Ok(data) => data,
+            Err(_) => continue,
+        };
+        if let Ok(parts) = parse_stat_file(&data) {
+            let status_data = get_all_data(task_path.join("status"), 16_385).unwrap_or_default();
+            p.threads
+                .entry(tid)
+                .or_insert_with(|| Thread::new(tid))
+                .update(&parts, &status_data, &task_path);
+            updated_tids.push(tid);
+        }
+    }
+    p.threads.retain(|tid, _| updated_tids.contains(tid));
+}
+
+fn get_open_files(path: &Path) -> Option<Vec<OpenFile>> {
+    let fd_path = path.join("fd");
+    let fdinfo_path = path.join("fdinfo");
+    let entries = fs::read_dir(&fd_path).ok()?;
+
+    let mut files = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let fd = entry.file_name().to_str()?.parse::<u32>().ok()?;
+            // The file descriptor might have been closed in the meantime.
+            let target = fs::read_link(entry.path()).ok()?;
+            let kind = get_file_kind(&entry.path(), &target);
+            let (flags, position) = get_fd_info(&fdinfo_path.join(entry.file_name()));
+            Some(OpenFile {
+                fd,
+                target,
+                kind,
+                flags,
+                position,
+            })
+        })
+        .collect::<Vec<_>>();
+    files.sort_unstable_by_key(|file| file.fd);
+    Some(files)
+}
+
+fn get_file_kind(fd_path: &Path, target: &Path) -> FileKind {
+    // Targets which aren't in the file system look like `type:[inode]` or `anon_inode:type`.
+    if let Some(target) = target.to_str().filter(|t| !t.starts_with('/')) {
+        let mut parts = target.splitn(2, ':');
+        let kind = parts.next().unwrap_or("");
+        let value = parts
+            .next()
+            .unwrap_or("")
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        return match kind {
+            "socket" => FileKind::Socket(value.parse().unwrap_or(0)),
+            "pipe" => FileKind::Pipe(value.parse().unwrap_or(0)),
+            "anon_inode" => FileKind::AnonInode(value.to_owned()),
+            _ => FileKind::Unknown,
+        };
+    }
+    match fs::metadata(fd_path).map(|m| m.file_type()) {
+        Ok(t) if t.is_file() => FileKind::File,
+        Ok(t) if t.is_dir() => FileKind::Directory,
+        Ok(t) if t.is_char_device() => FileKind::CharDevice,
+        Ok(t) if t.is_block_device() => FileKind::BlockDevice,
+        Ok(t) if t.is_fifo() => FileKind::Fifo,
+        _ => FileKind::Unknown,
+    }
+}
+
+/// Parses the content of `/proc/[pid]/smaps` (or `/proc/[pid]/smaps_rollup`) and returns the
+/// memory usage of all the mappings added together and the list of the mappings.
+fn parse_smaps(data: &str) -> (MemoryDetails, Vec<MemoryMap>) {
+    let mut details = MemoryDetails::default();
+    let mut maps: Vec<MemoryMap> = Vec::new();
+
+    for line in data.lines() {
+        let mut parts = line.splitn(2, ':');
+        let key = parts.next().unwrap_or("");
+        let value = match parts.next() {
+            // Lines of the mapping's header contain spaces before the first ':' (if any).
+            Some(value) if !key.contains(' ') => value,
+            _ => {
+                if let Some(map) = parse_memory_map_header(line) {
+                    maps.push(map);
+                }
+                continue;
+            }
+        };
+        let value = value
+            .trim()
+            .trim_end_matches(" kB")
+            .parse::<u64>()
+            .unwrap_or(0);
+        match key {
+            "Size" => {
+                if let Some(map) = maps.last_mut() {
+                    map.size = value;
+                }
+            }
+            "Rss" => {
+                details.rss += value;
+                if let Some(map) = maps.last_mut() {
+                    map.rss = value;
+                }
+            }
+            "Pss" => {
+                details.pss += value;
+                if let Some(map) = maps.last_mut() {
+                    map.pss = value;
+                }
+            }
+            "Shared_Clean" => details.shared_clean += value,
+            "Shared_Dirty" => details.shared_dirty += value,
+            "Private_Clean" => details.private_clean += value,
+            "Private_Dirty" => details.private_dirty += value,
+            "Swap" => details.swap += value,
+            "SwapPss" => details.swap_pss += value,
+            _ => {}
+        }
+    }
+    // The header of `smaps_rollup` isn't a real mapping.
+    maps.retain(|map| map.path != Path::new("[rollup]"));
+    (details, maps)
+}
+
+/// Parses a mapping's header which looks like this:
+///
+/// ```text
+/// 55c19ba43000-55c19ba45000 r--p 00000000 fe:00 317783                     /usr/bin/head
+/// ```
+fn parse_memory_map_header(line: &str) -> Option<MemoryMap> {
+    let mut parts = line.splitn(6, ' ');
+    let mut addresses = parts.next()?.splitn(2, '-');
+    let start = u64::from_str_radix(addresses.next()?, 16).ok()?;
+    let end = u64::from_str_radix(addresses.next()?, 16).ok()?;
+    let permissions = parts.next()?.to_owned();
+    Some(MemoryMap {
+        start,
+        end,
+        permissions,
+        // We skip the offset, the device and the inode.
+        path: PathBuf::from(parts.nth(3).unwrap_or("").trim_start()),
+        size: 0,
+        rss: 0,
+        pss: 0,
+    })
+}
+
+/// Parses the content of `/proc/[pid]/cgroup`, where each line looks like this:
+///
+/// ```text
+/// hierarchy-ID:controller-list:cgroup-path
+/// ```
+fn parse_cgroups(data: &str) -> Vec<Cgroup> {
+    data.lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ':');
+            let hierarchy_id = parts.next()?.parse().ok()?;
+            let controllers = parts
+                .next()?
+                .split(',')
+                .filter(|c| !c.is_empty())
+                .map(|c| c.to_owned())
+                .collect();
+            Some(Cgroup {
+                hierarchy_id,
+                controllers,
+                path: PathBuf::from(parts.next()?),
+            })
+        })
+        .collect()
+}
+
+/// Files of `/proc/[pid]/ns` for each namespace kind.
+const NAMESPACE_FILES: &[(&str, NamespaceKind)] = &[
+    ("cgroup", NamespaceKind::Cgroup),
+    ("ipc", NamespaceKind::Ipc),
+    ("mnt", NamespaceKind::Mnt),
+    ("net", NamespaceKind::Net),
+    ("pid", NamespaceKind::Pid),
+    ("time", NamespaceKind::Time),
+    ("user", NamespaceKind::User),
+    ("uts", NamespaceKind::Uts),
+];
+
+fn get_namespaces(path: &Path) -> Option<Vec<Namespace>> {
+    let ns_path = path.join("ns");
+    let namespaces = NAMESPACE_FILES
+        .iter()
+        .filter_map(|&(file, kind)| {
+            // The links look like `net:[4026531993]`. Not all the namespaces are supported by all
+            // the kernel versions so we ignore the missing ones.
+            let target = fs::read_link(ns_path.join(file)).ok()?;
+            let id = target
+                .to_str()?
+                .trim_end_matches(']')
+                .rsplit('[')
+                .next()?
+                .parse()
+                .ok()?;
+            Some(Namespace { kind, id })
+        })
+        .collect::<Vec<_>>();
+    // If we can't read any of them, it's very likely that we don't have enough rights.
+    if namespaces.is_empty() {
+        None
+    } else {
+        Some(namespaces)
+    }
+}
+
+/// Returns the ids of the processors, in the same order as the list returned by
+/// `SystemExt::get_processors`. They can differ from their index if some processors are offline.
+fn get_processor_ids() -> Vec<usize> {
+    get_all_data("/proc/stat", 4096)
+        .unwrap_or_default()
+        .lines()
+        // The first line is the "global" processor.
+        .skip(1)
+        .take_while(|line| line.starts_with("cpu"))
+        .filter_map(|line| {
+            line.split(' ')
+                .next()?
+                .trim_start_matches("cpu")
+                .parse()
+                .ok()
+        })
+        // `cpu_set_t` can only contain a limited number of processors.
+        .filter(|&id| id < mem::size_of::<cpu_set_t>() * 8)
+        .collect()
+}
+
+pub(crate) fn get_cpu_affinity(pid: Pid) -> Option<Vec<usize>> {
+    let mut set: cpu_set_t = unsafe { mem::zeroed() };
+    if unsafe { sched_getaffinity(pid, mem::size_of::<cpu_set_t>(), &mut set) } != 0 {
+        return None;
+    }
+    Some(
+        get_processor_ids()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, id)| unsafe { CPU_ISSET(id, &set) })
+            .map(|(index, _)| index)
+            .collect(),
+    )
+}
+
+pub(crate) fn set_cpu_affinity(pid: Pid, processors: &[usize]) -> io::Result<()> {
+    let ids = get_processor_ids();
+    let mut set: cpu_set_t = unsafe { mem::zeroed() };
+    for index in processors {
+        match ids.get(*index) {
+            Some(&id) => unsafe { CPU_SET(id, &mut set) },
+            None => return Err(io::Error::from_raw_os_error(EINVAL)),
+        }
+    }
+    if unsafe { sched_setaffinity(pid, mem::size_of::<cpu_set_t>(), &set) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Names of the resources in `/proc/[pid]/limits`.
+const RESOURCE_NAMES: &[(&str, Resource)] = &[
+    ("Max cpu time", Resource::CpuTime),
+    ("Max file size", Resource::FileSize),
+    ("Max data size", Resource::DataSize),
+    ("Max stack size", Resource::StackSize),
+    ("Max core file size", Resource::CoreFileSize),
+    ("Max resident set", Resource::ResidentSet),
+    ("Max processes", Resource::Processes),
+    ("Max open files", Resource::OpenFiles),
+    ("Max locked memory", Resource::LockedMemory),
+    ("Max address space", Resource::AddressSpace),
+    ("Max file locks", Resource::FileLocks),
+    ("Max pending signals", Resource::PendingSignals),
+    ("Max msgqueue size", Resource::MsgQueueSize),
+    ("Max nice priority", Resource::NicePriority),
+    ("Max realtime priority", Resource::RealtimePriority),
+    ("Max realtime timeout", Resource::RealtimeTimeout),
+];
+
+fn get_rlimit_resource(resource: Resource) -> c_int {
+    (match resource {
+        Resource::CpuTime => libc::RLIMIT_CPU,
+        Resource::FileSize => libc::RLIMIT_FSIZE,
+        Resource::DataSize => libc::RLIMIT_DATA,
+        Resource::StackSize => libc::RLIMIT_STACK,
+        Resource::CoreFileSize => libc::RLIMIT_CORE,
+        Resource::ResidentSet => libc::RLIMIT_RSS,
+        Resource::Processes => libc::RLIMIT_NPROC,
+        Resource::OpenFiles => libc::RLIMIT_NOFILE,
+        Resource::LockedMemory => libc::RLIMIT_MEMLOCK,
+        Resource::AddressSpace => libc::RLIMIT_AS,
+        Resource::FileLocks => libc::RLIMIT_LOCKS,
+        Resource::PendingSignals => libc::RLIMIT_SIGPENDING,
+        Resource::MsgQueueSize => libc::RLIMIT_MSGQUEUE,
+        Resource::NicePriority => libc::RLIMIT_NICE,
+        Resource::RealtimePriority => libc::RLIMIT_RTPRIO,
+        Resource::RealtimeTimeout => libc::RLIMIT_RTTIME,
+    }) as c_int
+}
+
+/// Parses the content of `/proc/[pid]/limits`:
+///
+/// ```text
+/// Limit                     Soft Limit           Hard Limit           Units
+/// Max cpu time              unlimited            unlimited            seconds
+/// Max open files            1024                 4096                 files
+/// ```
+fn parse_limits(data: &str) -> Vec<ResourceLimit> {
+    let parse_limit = |value: &str| -> Option<Limit> {
+        if value == "unlimited" {
+            Some(Limit::Unlimited)
+        } else {
+            value.parse().ok().map(Limit::Value)
+        }
+    };
+
+    data.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let &(name, resource) = RESOURCE_NAMES
+                .iter()
+                .find(|&&(name, _)| line.starts_with(name))?;
+            let mut values = line[name.len()..].split_whitespace();
+            Some(ResourceLimit {
+                resource,
+                soft: parse_limit(values.next()?)?,
+                hard: parse_limit(values.next()?)?,
+            })
+        })
+        .collect()
+}
+
+/// Returns the flags and the position of a file descriptor from `/proc/[pid]/fdinfo/[fd]`.
+fn get_fd_info(path: &Path) -> (u32, u64) {
+    let data = match get_all_data(path, 1024) {
+        Ok(d) => d,
+        Err(_) => return (0, 0),
+    };
+    let mut flags = 0;
+    let mut position = 0;
+    for line in data.lines() {
+        let mut parts = line.splitn(2, ':');
+        match (parts.next(), parts.next().map(|v| v.trim())) {
+            (Some("pos"), Some(value)) => position = value.parse().unwrap_or(0),
+            // The flags are written in octal.
+            (Some("flags"), Some(value)) => flags = u32::from_str_radix(value, 8).unwrap_or(0),
+            _ => continue,
+        }
+    }
+    (flags, position)
+}
+
+pub(crate) fn update_process_disk_activity(p: &mut Process, path: &Path) {
+    let mut path = PathBuf::from(path);
+    path.push("io");
+    let data = match get_all_data(&path, 16_384) {
+        Ok(d) => d,
+        Err(_) => return,
+    };
+    let mut done = 0;
+    for line in data.split('\n') {
+        let mut parts = line.split(": ");
+        match parts.next() {
+            Some("read_bytes") => {
+                p.old_read_bytes = p.read_bytes;
+                p.read_bytes = parts
+                    .next()
+                    .and_then(|x| x.parse::<u64>().ok())
+                    .unwrap_or(p.old_read_bytes);
+            }
+            Some("write_bytes") => {
+                p.old_written_bytes = p.written_bytes;
+                p.written_bytes = parts
+                    .next()
+                    .and_then(|x| x.parse::<u64>().ok())
+                    .unwrap_or(p.old_written_bytes);
+            }
+            _ => continue,
+        }
+        done += 1;
+        if done > 1 {
+            // No need to continue the reading.
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_update_from_status_data() {
+        let mut p = Process::new(1337, None, 0);
+        update_from_status_data(
+            &mut p,
+            "Name:\tsleep
+State:\tS (sleeping)
+VmPeak:\t    8400 kB
+VmHWM:\t     980 kB
+RssAnon:\t      88 kB
+RssFile:\t     892 kB
+VmSwap:\t      12 kB
+Threads:\t1
+voluntary_ctxt_switches:\t4
+nonvoluntary_ctxt_switches:\t2
+",
+        );
+        assert_eq!(p.memory_breakdown.peak_virtual_memory, 8400);
+        assert_eq!(p.memory_breakdown.peak_memory, 980);
+        assert_eq!(p.memory_breakdown.rss_anon, 88);
+        assert_eq!(p.memory_breakdown.rss_file, 892);
+        assert_eq!(p.memory_breakdown.swap, 12);
+        assert_eq!(p.thread_count, 1);
+        assert_eq!(p.activity.total_voluntary_context_switches, 4);
+        assert_eq!(p.activity.total_involuntary_context_switches, 2);
+
+        // Zombies don't have memory lines anymore.
+        update_from_status_data(
+            &mut p,
+            "Name:\tsleep
+State:\tZ (zombie)
+Threads:\t1
+voluntary_ctxt_switches:\t5
+nonvoluntary_ctxt_switches:\t2
+",
+        );
+        assert_eq!(p.memory_breakdown, MemoryBreakdown::default());
+        assert_eq!(p.activity.total_voluntary_context_switches, 5);
+        assert_eq!(p.activity.voluntary_context_switches, 1);
+    }
+}
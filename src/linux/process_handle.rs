@@ -0,0 +1,207 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::io;
+use std::os::unix::io::RawFd;
+use std::path::Path;
+use std::ptr::null;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use libc::{
+    c_int, c_long, c_uint, close, kill, poll, pollfd, siginfo_t, syscall, SYS_pidfd_open,
+    SYS_pidfd_send_signal, EBADF, EINTR, EIO, ENOSYS, ESRCH, POLLIN, POLLNVAL,
+};
+
+use super::system::{get_all_data, parse_stat_file};
+use utils::get_kill_error;
+use KillError;
+use Pid;
+use ProcessHandleExt;
+
+/// How long to sleep between two checks when waiting for a process without a pidfd.
+const WAIT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Struct referring to a process which cannot be confused with another one reusing its pid.
+pub struct ProcessHandle {
+    pid: Pid,
+    start_time: u64,
+    // `None` if the kernel doesn't support `pidfd_open` (added in Linux 5.3).
+    pidfd: Option<RawFd>,
+}
+
+impl ProcessHandle {
+    /// `start_time` is the start time of the process in clock ticks since boot, as read from
+    /// `/proc/[pid]/stat`.
+    pub(crate) fn new(pid: Pid, start_time: u64) -> io::Result<ProcessHandle> {
+        let fd = unsafe { syscall(SYS_pidfd_open, pid, 0 as c_uint) };
+        let pidfd = if fd >= 0 {
+            Some(fd as RawFd)
+        } else {
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() != Some(ENOSYS) {
+                return Err(err);
+            }
+            None
+        };
+        let handle = ProcessHandle {
+            pid,
+            start_time,
+            pidfd,
+        };
+        // The pid might have been reused before `pidfd_open` was called, in which case the
+        // pidfd refers to another process.
+        match get_state_and_start_time(pid) {
+            Some((_, start_time)) if start_time == handle.start_time => Ok(handle),
+            _ => Err(io::Error::from_raw_os_error(ESRCH)),
+        }
+    }
+
+    /// Returns the pidfd of the process, or `None` if the kernel doesn't support it.
+    ///
+    /// It becomes readable when the process exits, so it can be used with `poll` or `epoll`.
+    /// It is closed when the handle is dropped.
+    pub fn pidfd(&self) -> Option<RawFd> {
+        self.pidfd
+    }
+
+    /// Returns `true` if the pid still refers to the process and it didn't exit yet. Zombies
+    /// are considered as exited.
+    fn is_running(&self) -> bool {
+        match get_state_and_start_time(self.pid) {
+            Some((state, start_time)) => {
+                start_time == self.start_time && state != "Z" && state != "X"
+            }
+            None => false,
+        }
+    }
+
+    /// Waits until the process exits or until `deadline` is reached. Returns `true` if the
+    /// process exited.
+    fn wait_until(&self, deadline: Option<Instant>) -> io::Result<bool> {
+        loop {
+            let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+            let pidfd = match self.pidfd {
+                Some(pidfd) => pidfd,
+                None => {
+                    if !self.is_running() {
+                        return Ok(true);
+                    }
+                    match remaining {
+                        Some(remaining) if remaining == Duration::from_secs(0) => return Ok(false),
+                        Some(remaining) => thread::sleep(remaining.min(WAIT_INTERVAL)),
+                        None => thread::sleep(WAIT_INTERVAL),
+                    }
+                    continue;
+                }
+            };
+            let mut fds = pollfd {
+                fd: pidfd,
+                events: POLLIN,
+                revents: 0,
+            };
+            let timeout = match remaining {
+                Some(remaining) => to_poll_timeout(remaining),
+                None => -1,
+            };
+            match unsafe { poll(&mut fds, 1, timeout) } {
+                -1 => {
+                    let err = io::Error::last_os_error();
+                    if err.raw_os_error() != Some(EINTR) {
+                        return Err(err);
+                    }
+                }
+                // `poll` timed out but the deadline might be further than its maximum timeout.
+                0 => {
+                    if deadline.map(|d| Instant::now() >= d).unwrap_or(false) {
+                        return Ok(false);
+                    }
+                }
+                // The pidfd becomes readable once the process exited. `POLLHUP` is only set
+                // along with `POLLIN`, once the process has been reaped.
+                _ if fds.revents & POLLIN != 0 => return Ok(true),
+                _ if fds.revents & POLLNVAL != 0 => {
+                    return Err(io::Error::from_raw_os_error(EBADF))
+                }
+                // `POLLERR` or `POLLHUP` without `POLLIN`, it doesn't mean the process exited.
+                _ => return Err(io::Error::from_raw_os_error(EIO)),
+            }
+        }
+    }
+}
+
+impl ProcessHandleExt for ProcessHandle {
+    fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    fn try_kill(&self, signal: ::Signal) -> Result<(), KillError> {
+        let ret = match self.pidfd {
+            Some(pidfd) => unsafe {
+                syscall(
+                    SYS_pidfd_send_signal,
+                    pidfd,
+                    signal as c_int,
+                    null::<siginfo_t>(),
+                    0 as c_uint,
+                )
+            },
+            None => {
+                if !self.is_running() {
+                    return Err(KillError::NoSuchProcess);
+                }
+                unsafe { kill(self.pid, signal as c_int) as c_long }
+            }
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(get_kill_error(io::Error::last_os_error()))
+        }
+    }
+
+    fn has_exited(&self) -> bool {
+        self.wait_until(Some(Instant::now())).unwrap_or(false)
+    }
+
+    fn wait(&self) -> io::Result<()> {
+        self.wait_until(None).map(|_| ())
+    }
+
+    fn wait_timeout(&self, timeout: Duration) -> io::Result<bool> {
+        // If the deadline cannot be represented, it's as good as waiting forever.
+        self.wait_until(Instant::now().checked_add(timeout))
+    }
+}
+
+impl Drop for ProcessHandle {
+    fn drop(&mut self) {
+        if let Some(pidfd) = self.pidfd {
+            unsafe {
+                close(pidfd);
+            }
+        }
+    }
+}
+
+/// Returns the state and the start time (in clock ticks since boot) of the process.
+fn get_state_and_start_time(pid: Pid) -> Option<(String, u64)> {
+    let data = get_all_data(Path::new("/proc").join(pid.to_string()).join("stat"), 1024).ok()?;
+    let parts = parse_stat_file(&data).ok()?;
+    Some((
+        parts.get(2)?.to_string(),
+        u64::from_str(parts.get(21)?).ok()?,
+    ))
+}
+
+/// Converts `duration` into milliseconds for `poll`, rounded up so it doesn't return too early.
+///
+/// It is capped to a day, `wait_until` calls `poll` again if the deadline is further.
+fn to_poll_timeout(duration: Duration) -> c_int {
+    let duration = duration.min(Duration::from_secs(24 * 60 * 60));
+    (duration + Duration::from_nanos(999_999)).as_millis() as c_int
+}
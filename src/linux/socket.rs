@@ -0,0 +1,344 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::collections::HashMap;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+use super::system::get_all_data;
+use Pid;
+use Socket;
+use SocketProtocol;
+use SocketState;
+
+/// `__SO_ACCEPTCON` flag of `/proc/net/unix`, set on listening sockets.
+const SO_ACCEPTCON: u32 = 1 << 16;
+
+/// Returns the sockets listed in `/proc/net`, linked to the given processes.
+pub(crate) fn get_sockets<'a, I: Iterator<Item = &'a Pid>>(pids: I) -> Vec<Socket> {
+    let mut sockets = Vec::new();
+    for (file, protocol) in &[
+        ("tcp", SocketProtocol::Tcp),
+        ("tcp6", SocketProtocol::Tcp),
+        ("udp", SocketProtocol::Udp),
+        ("udp6", SocketProtocol::Udp),
+    ] {
+        if let Ok(data) = get_all_data(Path::new("/proc/net").join(file), 16_384) {
+            sockets.extend(parse_inet_sockets(&data, *protocol));
+        }
+    }
+    if let Ok(data) = get_all_data("/proc/net/unix", 16_384) {
+        sockets.extend(parse_unix_sockets(&data));
+    }
+
+    let mut owners = get_socket_owners(pids);
+    for socket in sockets.iter_mut() {
+        if let Some(pids) = owners.remove(&socket.inode) {
+            socket.pids = pids;
+        }
+    }
+    sockets
+}
+
+/// Returns the pids of the processes which have each socket (identified by its inode) open.
+fn get_socket_owners<'a, I: Iterator<Item = &'a Pid>>(pids: I) -> HashMap<u64, Vec<Pid>> {
+    let mut owners: HashMap<u64, Vec<Pid>> = HashMap::new();
+    for pid in pids {
+        let entries = match fs::read_dir(Path::new("/proc").join(pid.to_string()).join("fd")) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            // The file descriptor might have been closed in the meantime.
+            let target = match fs::read_link(entry.path()) {
+                Ok(target) => target,
+                Err(_) => continue,
+            };
+            // Sockets look like `socket:[inode]`.
+            let inode = target
+                .to_str()
+                .filter(|target| target.starts_with("socket:["))
+                .and_then(|target| {
+                    target
+                        .trim_start_matches("socket:[")
+                        .trim_end_matches(']')
+                        .parse::<u64>()
+                        .ok()
+                });
+            if let Some(inode) = inode {
+                owners.entry(inode).or_default().push(*pid);
+            }
+        }
+    }
+    for pids in owners.values_mut() {
+        // A process can have the same socket open more than once.
+        pids.sort_unstable();
+        pids.dedup();
+    }
+    owners
+}
+
+/// Parses the content of `/proc/net/{tcp,tcp6,udp,udp6}`.
+fn parse_inet_sockets(data: &str, protocol: SocketProtocol) -> Vec<Socket> {
+    // The first line contains the name of the columns.
+    data.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let parts = line.split_whitespace().collect::<Vec<_>>();
+            if parts.len() < 10 {
+                return None;
+            }
+            let mut queues = parts[4].splitn(2, ':');
+            Some(Socket {
+                protocol,
+                local_address: Some(parse_socket_address(parts[1])?),
+                remote_address: Some(parse_socket_address(parts[2])?),
+                path: None,
+                state: SocketState::from(u32::from_str_radix(parts[3], 16).ok()?),
+                inode: parts[9].parse().ok()?,
+                uid: parts[7].parse().ok(),
+                transmit_queue: u64::from_str_radix(queues.next()?, 16).unwrap_or(0),
+                receive_queue: u64::from_str_radix(queues.next()?, 16).unwrap_or(0),
+                pids: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Parses an address like `0100007F:1F90` (IPv4) or `00000000000000000000000001000000:1F90`
+/// (IPv6).
+///
+/// The address is written as a list of 32 bits integers in native endianness whereas the port
+/// is written as a regular number.
+fn parse_socket_address(address: &str) -> Option<SocketAddr> {
+    let mut parts = address.splitn(2, ':');
+    let ip = parts.next()?;
+    let port = u16::from_str_radix(parts.next()?, 16).ok()?;
+    let mut bytes = Vec::with_capacity(16);
+    for i in (0..ip.len()).step_by(8) {
+        let word = u32::from_str_radix(ip.get(i..i + 8)?, 16).ok()?;
+        bytes.extend_from_slice(&word.to_ne_bytes());
+    }
+    let ip = match bytes.len() {
+        4 => IpAddr::V4(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+        16 => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(&bytes);
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return None,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+/// Parses the content of `/proc/net/unix`, which looks like this:
+///
+/// ```text
+/// Num       RefCount Protocol Flags    Type St Inode Path
+/// 0000000000000000: 00000002 00000000 00010000 0001 01 18543 /run/systemd/private
+/// 0000000000000000: 00000003 00000000 00000000 0001 03   975
+/// ```
+fn parse_unix_sockets(data: &str) -> Vec<Socket> {
+    // The first line contains the name of the columns.
+    data.lines()
+        .skip(1)
+        .filter_map(|line| {
+            // The inode is padded with spaces and the path can contain spaces, so only the
+            // first 7 fields are split on whitespaces, the path is what remains.
+            let mut parts = Vec::with_capacity(7);
+            let mut rest = line;
+            while parts.len() < 7 {
+                rest = rest.trim_start();
+                let end = rest.find(' ').unwrap_or(rest.len());
+                if end == 0 {
+                    return None;
+                }
+                parts.push(&rest[..end]);
+                rest = &rest[end..];
+            }
+            // `rest` is either empty or starts with the space separating the inode from the path.
+            let path = rest.get(1..).unwrap_or("");
+            let flags = u32::from_str_radix(parts[3], 16).ok()?;
+            let state = if flags & SO_ACCEPTCON != 0 {
+                SocketState::Listen
+            } else {
+                match parts[5] {
+                    "02" => SocketState::SynSent,
+                    "03" => SocketState::Established,
+                    _ => SocketState::Close,
+                }
+            };
+            Some(Socket {
+                protocol: SocketProtocol::Unix,
+                local_address: None,
+                remote_address: None,
+                // Abstract sockets start with `@`.
+                path: if path.is_empty() {
+                    None
+                } else {
+                    Some(PathBuf::from(path))
+                },
+                state,
+                inode: parts[6].parse().ok()?,
+                uid: None,
+                transmit_queue: 0,
+                receive_queue: 0,
+                pids: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_parse_unix_sockets() {
+        let sockets = parse_unix_sockets(
+            "Num       RefCount Protocol Flags    Type St Inode Path
+0000000000000000: 00000002 00000000 00010000 0001 01 18543 /run/systemd/private
+0000000000000000: 00000003 00000000 00000000 0001 03   975
+0000000000000000: 00000002 00000000 00010000 0001 01 21334 @/tmp/.X11-unix/X0
+0000000000000000: 00000003 00000000 00000000 0001 03 40127 /run/user/1000/my socket
+0000000000000000: 00000002 00000000 00000000 0002 01    42
+0000000000000000: 00000002 00000000 00000000 0001 02 51020 /tmp/client.sock
+",
+        );
+        assert_eq!(sockets.len(), 6);
+        assert!(sockets.iter().all(|s| s.protocol == SocketProtocol::Unix
+            && s.local_address.is_none()
+            && s.uid.is_none()));
+
+        assert_eq!(sockets[0].inode, 18543);
+        assert_eq!(sockets[0].state, SocketState::Listen);
+        assert_eq!(
+            sockets[0].path.as_deref(),
+            Some(Path::new("/run/systemd/private"))
+        );
+        // Padded inode without path.
+        assert_eq!(sockets[1].inode, 975);
+        assert_eq!(sockets[1].state, SocketState::Established);
+        assert_eq!(sockets[1].path, None);
+        // Abstract socket.
+        assert_eq!(sockets[2].inode, 21334);
+        assert_eq!(
+            sockets[2].path.as_deref(),
+            Some(Path::new("@/tmp/.X11-unix/X0"))
+        );
+        // Path containing a space.
+        assert_eq!(sockets[3].inode, 40127);
+        assert_eq!(
+            sockets[3].path.as_deref(),
+            Some(Path::new("/run/user/1000/my socket"))
+        );
+        assert_eq!(sockets[4].inode, 42);
+        assert_eq!(sockets[4].state, SocketState::Close);
+        assert_eq!(sockets[5].state, SocketState::SynSent);
+    }
+
+    #[test]
+    fn check_parse_unix_sockets_invalid() {
+        let sockets = parse_unix_sockets(
+            "Num       RefCount Protocol Flags    Type St Inode Path
+0000000000000000: 00000002 00000000 00010000 0001 01
+0000000000000000: 00000002 00000000 0001000Z 0001 01 18543 /a
+",
+        );
+        assert!(sockets.is_empty());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn check_parse_inet_sockets() {
+        let sockets = parse_inet_sockets(
+            "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21589 1 0000000000000000 100 0 0 10 0
+   1: 0F02000A:0016 0202000A:C7A4 01 00000024:00000010 01:00000015 00000000  1000        0 40163 4 0000000000000000 20 4 29 10 -1
+",
+            SocketProtocol::Tcp,
+        );
+        assert_eq!(sockets.len(), 2);
+        assert_eq!(
+            sockets[0].local_address,
+            Some("127.0.0.1:631".parse().unwrap())
+        );
+        assert_eq!(
+            sockets[0].remote_address,
+            Some("0.0.0.0:0".parse().unwrap())
+        );
+        assert_eq!(sockets[0].state, SocketState::Listen);
+        assert_eq!(sockets[0].inode, 21589);
+        assert_eq!(sockets[0].uid, Some(0));
+        assert_eq!(
+            sockets[1].local_address,
+            Some("10.0.2.15:22".parse().unwrap())
+        );
+        assert_eq!(
+            sockets[1].remote_address,
+            Some("10.0.2.2:51108".parse().unwrap())
+        );
+        assert_eq!(sockets[1].state, SocketState::Established);
+        assert_eq!(sockets[1].transmit_queue, 0x24);
+        assert_eq!(sockets[1].receive_queue, 0x10);
+        assert_eq!(sockets[1].uid, Some(1000));
+        assert!(sockets
+            .iter()
+            .all(|s| s.protocol == SocketProtocol::Tcp && s.path.is_none() && s.pids.is_empty()));
+
+        let sockets = parse_inet_sockets(
+            "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000001000000:0277 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21588 1 0000000000000000 100 0 0 10 0
+   1: 0000000000000000FFFF00000F02000A:0050 0000000000000000FFFF00000202000A:D2F0 01 00000000:00000000 00:00000000 00000000    33        0 52311 1 0000000000000000 20 4 30 10 -1
+   2: B80D0120000000000000000001000000:1F90 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000  1000        0 61002 2 0000000000000000
+",
+            SocketProtocol::Udp,
+        );
+        assert_eq!(sockets.len(), 3);
+        assert_eq!(sockets[0].local_address, Some("[::1]:631".parse().unwrap()));
+        assert_eq!(sockets[0].remote_address, Some("[::]:0".parse().unwrap()));
+        assert_eq!(
+            sockets[1].local_address,
+            Some("[::ffff:10.0.2.15]:80".parse().unwrap())
+        );
+        assert_eq!(
+            sockets[1].remote_address,
+            Some("[::ffff:10.0.2.2]:54000".parse().unwrap())
+        );
+        assert_eq!(sockets[1].uid, Some(33));
+        assert_eq!(
+            sockets[2].local_address,
+            Some("[2001:db8::1]:8080".parse().unwrap())
+        );
+        assert_eq!(sockets[2].state, SocketState::Close);
+        assert_eq!(sockets[2].inode, 61002);
+        assert!(sockets.iter().all(|s| s.protocol == SocketProtocol::Udp));
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn check_parse_socket_address() {
+        assert_eq!(
+            parse_socket_address("0100007F:1F90"),
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(
+            parse_socket_address("B80D0120000000000000000001000000:0016"),
+            Some("[2001:db8::1]:22".parse().unwrap())
+        );
+        assert_eq!(
+            parse_socket_address("000080FE00000000FF5D5402A8F1CFFE:0222"),
+            Some("[fe80::254:5dff:fecf:f1a8]:546".parse().unwrap())
+        );
+        // Invalid lengths or values.
+        assert_eq!(parse_socket_address("0100007F"), None);
+        assert_eq!(parse_socket_address("01007F:1F90"), None);
+        assert_eq!(parse_socket_address("0100007F0100007F:1F90"), None);
+        assert_eq!(parse_socket_address("0100007G:1F90"), None);
+        assert_eq!(parse_socket_address("0100007F:10000"), None);
+    }
+}
@@ -5,20 +5,28 @@
 //
 
 use sys::component::{self, Component};
+use sys::disk;
 use sys::process::*;
 use sys::processor::*;
+use sys::socket;
 
+use BlockDevices;
+use Disk;
 use LoadAvg;
 use Networks;
 use Pid;
+use Socket;
+use Uid;
 use User;
-use {ProcessExt, RefreshKind, SystemExt};
+use {ProcessExt, RefreshKind, SystemExt, UserExt};
 
 use libc::{self, gid_t, sysconf, uid_t, _SC_CLK_TCK, _SC_PAGESIZE};
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fs::{self, File};
 use std::io::{self, BufRead, BufReader, Read};
+use std::os::unix::ffi::OsStringExt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
@@ -139,9 +147,12 @@ pub struct System {
     processors: Vec<Processor>,
     page_size_kb: u64,
     components: Vec<Component>,
+    disks: Vec<Disk>,
     networks: Networks,
+    block_devices: BlockDevices,
     uptime: u64,
     users: Vec<User>,
+    system_users: Vec<User>,
     boot_time: u64,
 }
 
@@ -294,9 +305,12 @@ impl SystemExt for System {
             processors: Vec::with_capacity(4),
             page_size_kb: unsafe { sysconf(_SC_PAGESIZE) as u64 / 1024 },
             components: Vec::new(),
+            disks: Vec::with_capacity(2),
             networks: Networks::new(),
+            block_devices: BlockDevices::new(),
             uptime: get_uptime(),
             users: Vec::new(),
+            system_users: Vec::new(),
             boot_time: boot_time(),
         };
         if !refreshes.cpu() {
@@ -345,7 +359,6 @@ impl SystemExt for System {
             &mut self.process_list,
             Path::new("/proc"),
             self.page_size_kb,
-            0,
             self.uptime,
             get_secs_since_epoch(),
         ) {
@@ -359,7 +372,6 @@ impl SystemExt for System {
             &Path::new("/proc/").join(pid.to_string()),
             &mut self.process_list,
             self.page_size_kb,
-            0,
             self.uptime,
             get_secs_since_epoch(),
         ) {
@@ -382,8 +394,14 @@ impl SystemExt for System {
         found
     }
 
+    fn refresh_disks_list(&mut self) {
+        self.disks = disk::get_all_disks();
+    }
+
     fn refresh_users_list(&mut self) {
-        self.users = crate::linux::users::get_users_list();
+        let (users, system_users) = crate::linux::users::get_users_list();
+        self.users = users;
+        self.system_users = system_users;
     }
 
     // COMMON PART
@@ -406,6 +424,14 @@ impl SystemExt for System {
         &mut self.networks
     }
 
+    fn get_block_devices(&self) -> &BlockDevices {
+        &self.block_devices
+    }
+
+    fn get_block_devices_mut(&mut self) -> &mut BlockDevices {
+        &mut self.block_devices
+    }
+
     fn get_global_processor_info(&self) -> &Processor {
         &self.global_processor
     }
@@ -455,6 +481,14 @@ impl SystemExt for System {
         &mut self.components
     }
 
+    fn get_disks(&self) -> &[Disk] {
+        &self.disks
+    }
+
+    fn get_disks_mut(&mut self) -> &mut [Disk] {
+        &mut self.disks
+    }
+
     fn get_uptime(&self) -> u64 {
         self.uptime
     }
@@ -487,6 +521,17 @@ impl SystemExt for System {
     fn get_users(&self) -> &[User] {
         &self.users
     }
+
+    fn get_user_by_id(&self, uid: Uid) -> Option<&User> {
+        self.users
+            .iter()
+            .chain(self.system_users.iter())
+            .find(|user| user.get_uid() == uid)
+    }
+
+    fn get_sockets(&self) -> Vec<Socket> {
+        socket::get_sockets(self.process_list.tasks.keys())
+    }
 }
 
 impl Default for System {
@@ -520,7 +565,6 @@ fn refresh_procs(
     proc_list: &mut Process,
     path: &Path,
     page_size_kb: u64,
-    pid: Pid,
     uptime: u64,
     now: u64,
 ) -> bool {
@@ -540,86 +584,44 @@ fn refresh_procs(
                 }
             })
             .collect::<Vec<_>>();
-        if pid == 0 {
-            let proc_list = Wrap(UnsafeCell::new(proc_list));
-            folders
-                .par_iter()
-                .filter_map(|e| {
-                    if let Ok((p, _)) = _get_process_data(
-                        e.as_path(),
-                        proc_list.get(),
-                        page_size_kb,
-                        pid,
-                        uptime,
-                        now,
-                    ) {
-                        p
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<_>>()
-        } else {
-            let mut updated_pids = Vec::with_capacity(folders.len());
-            let new_tasks = folders
-                .iter()
-                .filter_map(|e| {
-                    if let Ok((p, pid)) =
-                        _get_process_data(e.as_path(), proc_list, page_size_kb, pid, uptime, now)
-                    {
-                        updated_pids.push(pid);
-                        p
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<_>>();
-            // Sub-tasks are not cleaned up outside so we do it here directly.
-            proc_list
-                .tasks
-                .retain(|&pid, _| updated_pids.iter().any(|&x| x == pid));
-            new_tasks
-        }
-        .into_iter()
-        .for_each(|e| {
-            proc_list.tasks.insert(e.pid(), e);
-        });
+        let proc_list = Wrap(UnsafeCell::new(proc_list));
+        folders
+            .par_iter()
+            .filter_map(|e| {
+                if let Ok((p, _)) =
+                    _get_process_data(e.as_path(), proc_list.get(), page_size_kb, uptime, now)
+                {
+                    p
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|e| {
+                proc_list.get().tasks.insert(e.pid(), e);
+            });
         true
     } else {
         false
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn update_time_and_memory(
-    path: &Path,
-    entry: &mut Process,
-    parts: &[&str],
-    page_size_kb: u64,
-    parent_memory: u64,
-    parent_virtual_memory: u64,
-    pid: Pid,
-    uptime: u64,
-    now: u64,
-) {
-    {
-        // rss
-        entry.memory = u64::from_str(parts[23]).unwrap_or(0) * page_size_kb;
-        if entry.memory >= parent_memory {
-            entry.memory -= parent_memory;
-        }
-        // vsz
-        entry.virtual_memory = u64::from_str(parts[22]).unwrap_or(0) * page_size_kb;
-        if entry.virtual_memory >= parent_virtual_memory {
-            entry.virtual_memory -= parent_virtual_memory;
-        }
-        set_time(
-            entry,
-            u64::from_str(parts[13]).unwrap_or(0),
-            u64::from_str(parts[14]).unwrap_or(0),
-        );
-    }
-    refresh_procs(entry, &path.join("task"), page_size_kb, pid, uptime, now);
+fn update_time_and_memory(entry: &mut Process, parts: &[&str], page_size_kb: u64) {
+    // rss
+    entry.memory = u64::from_str(parts[23]).unwrap_or(0) * page_size_kb;
+    // vsz
+    entry.virtual_memory = u64::from_str(parts[22]).unwrap_or(0) * page_size_kb;
+    set_time(
+        entry,
+        u64::from_str(parts[13]).unwrap_or(0),
+        u64::from_str(parts[14]).unwrap_or(0),
+    );
+    entry.priority = i64::from_str(parts[17]).unwrap_or(0);
+    entry.nice = i32::from_str(parts[18]).unwrap_or(0);
+    // These fields are only available since Linux 2.5.19.
+    entry.realtime_priority = parts.get(39).and_then(|x| x.parse().ok()).unwrap_or(0);
+    entry.scheduling_policy = parts.get(40).and_then(|x| x.parse().ok()).unwrap_or(0);
 }
 
 macro_rules! unwrap_or_return {
@@ -631,14 +633,23 @@ macro_rules! unwrap_or_return {
     }};
 }
 
-fn _get_uid_and_gid(status_data: String) -> Option<(uid_t, gid_t)> {
+/// The real and effective ids read from the `Uid:` or `Gid:` line of `/proc/[pid]/status`.
+struct Ids<T> {
+    real: T,
+    effective: T,
+}
+
+fn _get_uid_and_gid(status_data: &str) -> Option<(Ids<uid_t>, Ids<gid_t>)> {
     // We're only interested in the lines starting with Uid: and Gid:
-    // here. From these lines, we're looking at the second entry to get
-    // the effective u/gid.
+    // here. From these lines, we're looking at the first entry to get
+    // the real u/gid and at the second one to get the effective u/gid.
 
-    let f = |h: &str, n: &str| -> Option<uid_t> {
+    let f = |h: &str, n: &str| -> Option<Ids<uid_t>> {
         if h.starts_with(n) {
-            h.split_whitespace().nth(2).unwrap_or("0").parse().ok()
+            let mut ids = h.split_whitespace().skip(1);
+            let real = ids.next().unwrap_or("0").parse().ok()?;
+            let effective = ids.next().unwrap_or("0").parse().ok()?;
+            Some(Ids { real, effective })
         } else {
             None
         }
@@ -665,7 +676,19 @@ fn _get_uid_and_gid(status_data: String) -> Option<(uid_t, gid_t)> {
     }
 }
 
-fn parse_stat_file(data: &str) -> Result<Vec<&str>, ()> {
+/// Updates the user and group ids, the memory breakdown and the number of threads of the process
+/// from `/proc/[pid]/status`.
+fn update_from_status(p: &mut Process, status_data: &str) {
+    update_from_status_data(p, status_data);
+    if let Some((uid, gid)) = _get_uid_and_gid(status_data) {
+        p.uid = uid.effective;
+        p.gid = gid.effective;
+        p.real_uid = uid.real;
+        p.real_gid = gid.real;
+    }
+}
+
+pub(crate) fn parse_stat_file(data: &str) -> Result<Vec<&str>, ()> {
     // The stat file is "interesting" to parse, because spaces cannot
     // be used as delimiters. The second field stores the command name
     // surrounded by parentheses. Unfortunately, whitespace and
@@ -722,12 +745,11 @@ fn _get_process_data(
     path: &Path,
     proc_list: &mut Process,
     page_size_kb: u64,
-    pid: Pid,
     uptime: u64,
     now: u64,
 ) -> Result<(Option<Process>, Pid), ()> {
     let nb = match path.file_name().and_then(|x| x.to_str()).map(Pid::from_str) {
-        Some(Ok(nb)) if nb != pid => nb,
+        Some(Ok(nb)) => nb,
         _ => return Err(()),
     };
 
@@ -738,8 +760,6 @@ fn _get_process_data(
             .map(ProcessStatus::from)
             .unwrap_or_else(|| ProcessStatus::Unknown(0));
     };
-    let parent_memory = proc_list.memory;
-    let parent_virtual_memory = proc_list.virtual_memory;
     if let Some(ref mut entry) = proc_list.tasks.get_mut(&nb) {
         let data = if let Some(ref mut f) = entry.stat_file {
             get_all_data_from_file(f, 1024).map_err(|_| ())?
@@ -752,18 +772,13 @@ fn _get_process_data(
             data
         };
         let parts = parse_stat_file(&data)?;
+        let status_data = get_all_data(path.join("status"), 16_385).unwrap_or_default();
         get_status(entry, parts[2]);
-        update_time_and_memory(
-            path,
-            entry,
-            &parts,
-            page_size_kb,
-            parent_memory,
-            parent_virtual_memory,
-            nb,
-            uptime,
-            now,
-        );
+        update_from_status(entry, &status_data);
+        update_process_disk_activity(entry, path);
+        update_activity(entry, &parts, unsafe { sysconf(_SC_CLK_TCK) } as u64);
+        update_time_and_memory(entry, &parts, page_size_kb);
+        update_threads(entry, path);
         return Ok((None, nb));
     }
 
@@ -775,118 +790,85 @@ fn _get_process_data(
     let stat_file = check_nb_open_files(file);
     let parts = parse_stat_file(&data)?;
 
-    let parent_pid = if proc_list.pid != 0 {
-        Some(proc_list.pid)
-    } else {
-        match Pid::from_str(parts[3]) {
-            Ok(p) if p != 0 => Some(p),
-            _ => None,
-        }
+    let parent_pid = match Pid::from_str(parts[3]) {
+        Ok(p) if p != 0 => Some(p),
+        _ => None,
     };
 
     let clock_cycle = unsafe { sysconf(_SC_CLK_TCK) } as u64;
     let since_boot = u64::from_str(parts[21]).unwrap_or(0) / clock_cycle;
     let start_time = now.saturating_sub(uptime.saturating_sub(since_boot));
     let mut p = Process::new(nb, parent_pid, start_time);
+    p.start_ticks = u64::from_str(parts[21]).unwrap_or(0);
 
     p.stat_file = stat_file;
     get_status(&mut p, parts[2]);
 
+    let status_data = get_all_data(path.join("status"), 16_385).unwrap_or_default();
+    update_from_status(&mut p, &status_data);
+
     tmp.pop();
-    tmp.push("status");
-    if let Ok(data) = get_all_data(&tmp, 16_385) {
-        if let Some((uid, gid)) = _get_uid_and_gid(data) {
-            p.uid = uid;
-            p.gid = gid;
+    tmp.push("cmdline");
+    p.cmd_os = copy_from_file(&tmp);
+    p.cmd = to_strings(&p.cmd_os);
+    tmp.pop();
+    tmp.push("exe");
+    match tmp.read_link() {
+        Ok(exe_path) => {
+            p.name = exe_path
+                .file_name()
+                .and_then(|s| {
+                    let s: &str = s.to_str()?;
+                    Some(s.to_owned())
+                })
+                .unwrap_or_else(|| get_exe_name(&p));
+            p.exe = exe_path;
+        }
+        Err(_) => {
+            p.exe = PathBuf::new();
+            p.name = get_exe_name(&p);
         }
     }
+    tmp.pop();
+    tmp.push("environ");
+    p.environ_os = copy_from_file(&tmp);
+    p.environ = to_strings(&p.environ_os);
+    tmp.pop();
+    tmp.push("cwd");
+    p.cwd = realpath(&tmp);
+    tmp.pop();
+    tmp.push("root");
+    p.root = realpath(&tmp);
 
-    if proc_list.pid != 0 {
-        // If we're getting information for a child, no need to get those info since we
-        // already have them...
-        p.cmd = proc_list.cmd.clone();
-        p.name = proc_list.name.clone();
-        p.environ = proc_list.environ.clone();
-        p.exe = proc_list.exe.clone();
-        p.cwd = proc_list.cwd.clone();
-        p.root = proc_list.root.clone();
-    } else {
-        tmp.pop();
-        tmp.push("cmdline");
-        p.cmd = copy_from_file(&tmp);
-        tmp.pop();
-        tmp.push("exe");
-        match tmp.read_link() {
-            Ok(exe_path) => {
-                p.name = exe_path
-                    .file_name()
-                    .and_then(|s| {
-                        let s: &str = s.to_str()?;
-                        Some(s.to_owned())
-                    })
-                    .unwrap_or_else(|| get_exe_name(&p));
-                p.exe = exe_path;
-            }
-            Err(_) => {
-                p.exe = PathBuf::new();
-                p.name = get_exe_name(&p);
-            }
-        }
-        tmp.pop();
-        tmp.push("environ");
-        p.environ = copy_from_file(&tmp);
-        tmp.pop();
-        tmp.push("cwd");
-        p.cwd = realpath(&tmp);
-        tmp.pop();
-        tmp.push("root");
-        p.root = realpath(&tmp);
-    }
-
-    update_time_and_memory(
-        path,
-        &mut p,
-        &parts,
-        page_size_kb,
-        proc_list.memory,
-        proc_list.virtual_memory,
-        nb,
-        uptime,
-        now,
-    );
+    update_process_disk_activity(&mut p, path);
+    update_activity(&mut p, &parts, clock_cycle);
+    update_time_and_memory(&mut p, &parts, page_size_kb);
+    update_threads(&mut p, path);
     Ok((Some(p), nb))
 }
 
-fn copy_from_file(entry: &Path) -> Vec<String> {
-    match File::open(entry.to_str().unwrap_or("/")) {
-        Ok(mut f) => {
-            let mut data = vec![0; 16_384];
-
-            if let Ok(size) = f.read(&mut data) {
-                data.truncate(size);
-                let mut out = Vec::with_capacity(20);
-                let mut start = 0;
-                for (pos, x) in data.iter().enumerate() {
-                    if *x == 0 {
-                        if pos - start >= 1 {
-                            if let Ok(s) = ::std::str::from_utf8(&data[start..pos])
-                                .map(|x| x.trim().to_owned())
-                            {
-                                out.push(s);
-                            }
-                        }
-                        start = pos + 1; // to keeping prevent '\0'
-                    }
-                }
-                out
-            } else {
-                Vec::new()
-            }
-        }
+/// Reads a file containing null-separated values (like `/proc/[pid]/cmdline`). The whole file
+/// is read, no matter how big it is.
+fn copy_from_file(entry: &Path) -> Vec<OsString> {
+    match fs::read(entry) {
+        Ok(data) => data
+            .split(|x| *x == 0)
+            .filter(|x| !x.is_empty())
+            .map(|x| OsString::from_vec(x.to_vec()))
+            .collect(),
         Err(_) => Vec::new(),
     }
 }
 
+/// Converts the values read by `copy_from_file` into strings. Invalid UTF-8 sequences are
+/// replaced with `U+FFFD REPLACEMENT CHARACTER`.
+fn to_strings(values: &[OsString]) -> Vec<String> {
+    values
+        .iter()
+        .map(|x| x.to_string_lossy().trim().to_owned())
+        .collect()
+}
+
 fn get_all_data_from_file(file: &mut File, size: usize) -> io::Result<String> {
     use std::io::Seek;
     let mut buf = String::with_capacity(size);
@@ -0,0 +1,123 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::io;
+use std::path::Path;
+
+use super::process::{get_cpu_affinity, set_cpu_affinity, ProcessStatus};
+use super::system::get_all_data;
+use Pid;
+use ThreadExt;
+
+/// Struct containing a thread's information.
+pub struct Thread {
+    tid: Pid,
+    name: String,
+    status: ProcessStatus,
+    utime: u64,
+    stime: u64,
+    old_utime: u64,
+    old_stime: u64,
+    cpu_usage: f32,
+    voluntary_context_switches: u64,
+    involuntary_context_switches: u64,
+}
+
+impl Thread {
+    pub(crate) fn new(tid: Pid) -> Thread {
+        Thread {
+            tid,
+            name: String::new(),
+            status: ProcessStatus::Unknown(0),
+            utime: 0,
+            stime: 0,
+            old_utime: 0,
+            old_stime: 0,
+            cpu_usage: 0.,
+            voluntary_context_switches: 0,
+            involuntary_context_switches: 0,
+        }
+    }
+
+    pub(crate) fn compute_cpu_usage(&mut self, nb_processors: u64, total_time: f32) {
+        let time =
+            self.utime.saturating_sub(self.old_utime) + self.stime.saturating_sub(self.old_stime);
+        self.cpu_usage = (time * nb_processors * 100) as f32 / total_time;
+    }
+
+    /// Updates the thread from the (already split) content of `/proc/[pid]/task/[tid]/stat` and
+    /// the content of `/proc/[pid]/task/[tid]/status`. Its name is read from
+    /// `/proc/[pid]/task/[tid]/comm` since it can be changed at any time.
+    pub(crate) fn update(&mut self, stat_parts: &[&str], status_data: &str, path: &Path) {
+        self.status = stat_parts[2]
+            .chars()
+            .next()
+            .map(ProcessStatus::from)
+            .unwrap_or_else(|| ProcessStatus::Unknown(0));
+        self.old_utime = self.utime;
+        self.old_stime = self.stime;
+        self.utime = stat_parts[13].parse().unwrap_or(0);
+        self.stime = stat_parts[14].parse().unwrap_or(0);
+        if let Ok(name) = get_all_data(path.join("comm"), 16) {
+            let name = name.trim_end_matches('\n');
+            if self.name != name {
+                self.name = name.to_owned();
+            }
+        }
+        for line in status_data.lines() {
+            let mut parts = line.splitn(2, ':');
+            let (key, value) = match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => (key, value.trim()),
+                _ => continue,
+            };
+            match key {
+                "voluntary_ctxt_switches" => {
+                    self.voluntary_context_switches = value.parse().unwrap_or(0);
+                }
+                "nonvoluntary_ctxt_switches" => {
+                    self.involuntary_context_switches = value.parse().unwrap_or(0);
+                    // This is the last line we're interested into.
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl ThreadExt for Thread {
+    fn tid(&self) -> Pid {
+        self.tid
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> ProcessStatus {
+        self.status
+    }
+
+    fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    fn voluntary_context_switches(&self) -> u64 {
+        self.voluntary_context_switches
+    }
+
+    fn involuntary_context_switches(&self) -> u64 {
+        self.involuntary_context_switches
+    }
+
+    fn cpu_affinity(&self) -> Option<Vec<usize>> {
+        get_cpu_affinity(self.tid)
+    }
+
+    fn set_cpu_affinity(&self, processors: &[usize]) -> io::Result<()> {
+        set_cpu_affinity(self.tid, processors)
+    }
+}
@@ -10,7 +10,9 @@ use libc::{getgrgid, getgrouplist};
 use std::fs::File;
 use std::io::Read;
 
-pub fn get_users_list() -> Vec<User> {
+/// Returns the "real" users (with a login shell) and the system users (like daemon accounts),
+/// which are only used to resolve user ids.
+pub fn get_users_list() -> (Vec<User>, Vec<User>) {
     let mut s = String::new();
     let mut ngroups = 100;
     let mut groups = vec![0; ngroups as usize];
@@ -20,17 +22,16 @@ pub fn get_users_list() -> Vec<User> {
         .filter_map(|line| {
             let mut parts = line.split(':');
             if let Some(username) = parts.next() {
-                let mut parts = parts.skip(2);
-                if let Some(group_id) = parts.next().and_then(|x| u32::from_str_radix(x, 10).ok()) {
+                let mut parts = parts.skip(1);
+                let user_id = parts.next().and_then(|x| x.parse::<u32>().ok());
+                let group_id = parts.next().and_then(|x| x.parse::<u32>().ok());
+                if let (Some(user_id), Some(group_id)) = (user_id, group_id) {
                     if let Some(command) = parts.last() {
-                        if command.is_empty()
+                        // We don't want "fake" users in the users list so in case the user
+                        // command is "bad", this user is a system one.
+                        let is_system = command.is_empty()
                             || command.ends_with("/false")
-                            || command.ends_with("/nologin")
-                        {
-                            // We don't want "fake" users so in case the user command is "bad", we
-                            // ignore this user.
-                            return None;
-                        }
+                            || command.ends_with("/nologin");
                         let mut c_user = username.as_bytes().to_vec();
                         c_user.push(0);
                         loop {
@@ -53,7 +54,9 @@ pub fn get_users_list() -> Vec<User> {
                                 return None;
                             }
                             // Let's get all the group names!
-                            return Some(User {
+                            let user = User {
+                                uid: user_id,
+                                gid: group_id,
                                 name: username.to_owned(),
                                 groups: groups[..current as usize]
                                     .iter()
@@ -76,12 +79,23 @@ pub fn get_users_list() -> Vec<User> {
                                         String::from_utf8(group_name).ok()
                                     })
                                     .collect(),
-                            });
+                            };
+                            return Some((user, is_system));
                         }
                     }
                 }
             }
             None
         })
-        .collect()
+        .fold(
+            (Vec::new(), Vec::new()),
+            |(mut users, mut system_users), (user, is_system)| {
+                if is_system {
+                    system_users.push(user);
+                } else {
+                    users.push(user);
+                }
+                (users, system_users)
+            },
+        )
 }
@@ -0,0 +1,177 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2017 Guillaume Gomez
+//
+
+use utils::to_cpath;
+use DiskExt;
+use DiskKind;
+
+use libc::{c_char, c_int, statfs};
+use std::ffi::{CStr, OsStr, OsString};
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+
+/// Struct containing a disk information.
+pub struct Disk {
+    kind: DiskKind,
+    name: OsString,
+    file_system: Vec<u8>,
+    mount_point: PathBuf,
+    total_space: u64,
+    available_space: u64,
+    total_inodes: u64,
+    free_inodes: u64,
+    mount_options: Vec<String>,
+    device_id: (u32, u32),
+}
+
+impl DiskExt for Disk {
+    fn get_kind(&self) -> DiskKind {
+        self.kind
+    }
+
+    fn get_name(&self) -> &OsStr {
+        &self.name
+    }
+
+    fn get_file_system(&self) -> &[u8] {
+        &self.file_system
+    }
+
+    fn get_mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+
+    fn get_total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    fn get_available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    fn get_total_inodes(&self) -> u64 {
+        self.total_inodes
+    }
+
+    fn get_free_inodes(&self) -> u64 {
+        self.free_inodes
+    }
+
+    fn get_mount_options(&self) -> &[String] {
+        &self.mount_options
+    }
+
+    fn get_device_id(&self) -> (u32, u32) {
+        self.device_id
+    }
+
+    fn refresh(&mut self) -> bool {
+        let mount_point_cpath = to_cpath(&self.mount_point);
+        unsafe {
+            let mut stat: statfs = mem::zeroed();
+            if libc::statfs(mount_point_cpath.as_ptr() as *const c_char, &mut stat) == 0 {
+                self.available_space = u64::from(stat.f_bsize) * stat.f_bavail;
+                self.free_inodes = stat.f_ffree;
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
+
+fn get_mount_options(flags: u32) -> Vec<String> {
+    let mut options = Vec::new();
+    options.push(if flags & libc::MNT_RDONLY as u32 != 0 {
+        "ro".to_owned()
+    } else {
+        "rw".to_owned()
+    });
+    for &(flag, name) in &[
+        (libc::MNT_NOEXEC, "noexec"),
+        (libc::MNT_NOSUID, "nosuid"),
+        (libc::MNT_NODEV, "nodev"),
+        (libc::MNT_SYNCHRONOUS, "sync"),
+        (libc::MNT_ASYNC, "async"),
+        (libc::MNT_NOATIME, "noatime"),
+    ] {
+        if flags & flag as u32 != 0 {
+            options.push(name.to_owned());
+        }
+    }
+    options
+}
+
+/// Returns the major and minor numbers of the device the given mount point is on.
+fn get_device_id(mount_point: &Path) -> (u32, u32) {
+    let mount_point_cpath = to_cpath(mount_point);
+    unsafe {
+        let mut stat: libc::stat = mem::zeroed();
+        if libc::stat(mount_point_cpath.as_ptr() as *const c_char, &mut stat) == 0 {
+            let dev = stat.st_dev as u32;
+            ((dev >> 24) & 0xff, dev & 0x00ff_ffff)
+        } else {
+            (0, 0)
+        }
+    }
+}
+
+unsafe fn c_buf_to_os_string(buf: &[c_char]) -> OsString {
+    OsStr::from_bytes(CStr::from_ptr(buf.as_ptr()).to_bytes()).to_owned()
+}
+
+pub(crate) fn get_disks() -> Vec<Disk> {
+    unsafe {
+        let count = libc::getfsstat(ptr::null_mut(), 0, libc::MNT_NOWAIT);
+        if count < 1 {
+            return Vec::new();
+        }
+        let mut fs_infos: Vec<statfs> = Vec::with_capacity(count as usize);
+        let count = libc::getfsstat(
+            fs_infos.as_mut_ptr(),
+            (count as usize * mem::size_of::<statfs>()) as c_int,
+            libc::MNT_NOWAIT,
+        );
+        if count < 1 {
+            return Vec::new();
+        }
+        fs_infos.set_len(count as usize);
+
+        fs_infos
+            .iter()
+            .filter_map(|stat| {
+                // Volumes hidden from the Finder (like the `/dev` or the `/System/Volumes/VM`
+                // mounts) are not interesting for us.
+                if stat.f_flags & libc::MNT_DONTBROWSE as u32 != 0 {
+                    return None;
+                }
+                let total_space = u64::from(stat.f_bsize) * stat.f_blocks;
+                if total_space == 0 {
+                    return None;
+                }
+                let mount_point = PathBuf::from(c_buf_to_os_string(&stat.f_mntonname));
+                Some(Disk {
+                    // Finding out the disk kind requires to go through IOKit, which isn't
+                    // supported yet.
+                    kind: DiskKind::Unknown(-1),
+                    name: c_buf_to_os_string(&stat.f_mntfromname),
+                    file_system: CStr::from_ptr(stat.f_fstypename.as_ptr())
+                        .to_bytes()
+                        .to_vec(),
+                    device_id: get_device_id(&mount_point),
+                    mount_point,
+                    total_space,
+                    available_space: u64::from(stat.f_bsize) * stat.f_bavail,
+                    total_inodes: stat.f_files,
+                    free_inodes: stat.f_ffree,
+                    mount_options: get_mount_options(stat.f_flags),
+                })
+            })
+            .collect()
+    }
+}
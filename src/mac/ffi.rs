@@ -104,6 +104,7 @@ extern "C" {
     // pub fn ODRecordGetRecordName(record: ODRecordRef) -> CFStringRef;
 
     pub fn mach_absolute_time() -> u64;
+    pub fn mach_timebase_info(info: *mut mach_timebase_info) -> c_int;
     //pub fn task_for_pid(host: u32, pid: pid_t, task: *mut task_t) -> u32;
     pub fn mach_task_self() -> u32;
     pub fn mach_host_self() -> u32;
@@ -447,6 +448,13 @@ pub struct xsw_usage {
     pub xsu_encrypted: boolean_t,
 }
 
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct mach_timebase_info {
+    pub numer: u32,
+    pub denom: u32,
+}
+
 //https://github.com/andrewdavidmackenzie/libproc-rs/blob/master/src/libproc/pid_rusage.rs
 #[derive(Debug, Default)]
 #[repr(C)]
@@ -468,6 +476,8 @@ pub struct RUsageInfoV2 {
     pub ri_child_interrupt_wkups: u64,
     pub ri_child_pageins: u64,
     pub ri_child_elapsed_abstime: u64,
+    pub ri_diskio_bytesread: u64,
+    pub ri_diskio_byteswritten: u64,
 }
 
 //pub const HOST_CPU_LOAD_INFO_COUNT: usize = 4;
@@ -489,6 +499,8 @@ pub const HW_MEMSIZE: u32 = 24;
 //pub const PROC_ALL_PIDS: c_uint = 1;
 pub const PROC_PIDTBSDINFO: c_int = 3;
 
+pub const RUSAGE_INFO_V2: c_int = 2;
+
 //pub const TASK_THREAD_TIMES_INFO: u32 = 3;
 //pub const TASK_THREAD_TIMES_INFO_COUNT: u32 = 4;
 //pub const TASK_BASIC_INFO_64: u32 = 5;
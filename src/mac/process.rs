@@ -5,21 +5,45 @@
 //
 
 use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fmt;
+use std::io;
 use std::mem::{self, MaybeUninit};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use libc::{c_int, c_void, gid_t, kill, size_t, uid_t};
 
+use Cgroup;
+use CpuTime;
+use DiskUsage;
+use Gid;
+use KillError;
+use Limit;
+use MemoryBreakdown;
+use MemoryDetails;
+use MemoryMap;
+use Namespace;
+use OpenFile;
 use Pid;
+use ProcessActivity;
 use ProcessExt;
+use ProcessId;
+use Resource;
+use ResourceLimit;
+use SchedulingPolicy;
+use Uid;
 
 use sys::ffi;
+use sys::process_handle::ProcessHandle;
 use sys::system::Wrap;
+use sys::thread::Thread;
+use utils::get_kill_error;
 
 /// Enum describing the different status of a process.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ProcessStatus {
     /// Process being created by fork.
     Idle,
@@ -135,6 +159,10 @@ pub struct Process {
     stime: u64,
     old_utime: u64,
     old_stime: u64,
+    user_time: u64,
+    system_time: u64,
+    old_user_time: u64,
+    old_system_time: u64,
     start_time: u64,
     updated: bool,
     cpu_usage: f32,
@@ -142,6 +170,10 @@ pub struct Process {
     pub uid: uid_t,
     /// Group id of the process owner.
     pub gid: gid_t,
+    pub(crate) real_uid: uid_t,
+    pub(crate) real_gid: gid_t,
+    threads: HashMap<Pid, Thread>,
+    thread_count: u64,
     pub(crate) process_status: ProcessStatus,
     /// Status of process (running, stopped, waiting, etc). `None` means `sysinfo` doesn't have
     /// enough rights to get this information.
@@ -172,10 +204,18 @@ impl Process {
             stime: 0,
             old_utime: 0,
             old_stime: 0,
+            user_time: 0,
+            system_time: 0,
+            old_user_time: 0,
+            old_system_time: 0,
             updated: true,
             start_time: 0,
             uid: 0,
             gid: 0,
+            real_uid: 0,
+            real_gid: 0,
+            threads: HashMap::new(),
+            thread_count: 0,
             process_status: ProcessStatus::Unknown(0),
             status: None,
             old_read_bytes: 0,
@@ -212,10 +252,18 @@ impl Process {
             stime: 0,
             old_utime: 0,
             old_stime: 0,
+            user_time: 0,
+            system_time: 0,
+            old_user_time: 0,
+            old_system_time: 0,
             updated: true,
             start_time,
             uid: 0,
             gid: 0,
+            real_uid: 0,
+            real_gid: 0,
+            threads: HashMap::new(),
+            thread_count: 0,
             process_status: ProcessStatus::Unknown(0),
             status: None,
             old_read_bytes: 0,
@@ -244,10 +292,18 @@ impl ProcessExt for Process {
             stime: 0,
             old_utime: 0,
             old_stime: 0,
+            user_time: 0,
+            system_time: 0,
+            old_user_time: 0,
+            old_system_time: 0,
             updated: true,
             start_time,
             uid: 0,
             gid: 0,
+            real_uid: 0,
+            real_gid: 0,
+            threads: HashMap::new(),
+            thread_count: 0,
             process_status: ProcessStatus::Unknown(0),
             status: None,
             old_read_bytes: 0,
@@ -257,8 +313,15 @@ impl ProcessExt for Process {
         }
     }
 
-    fn kill(&self, signal: ::Signal) -> bool {
-        unsafe { kill(self.pid, signal as c_int) == 0 }
+    fn try_kill(&self, signal: ::Signal) -> Result<(), KillError> {
+        if unsafe { kill(self.pid, signal as c_int) } == 0 {
+            return Ok(());
+        }
+        Err(get_kill_error(io::Error::last_os_error()))
+    }
+
+    fn handle(&self) -> io::Result<ProcessHandle> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
     }
 
     fn name(&self) -> &str {
@@ -281,6 +344,14 @@ impl ProcessExt for Process {
         &self.environ
     }
 
+    fn cmd_os(&self) -> Option<&[OsString]> {
+        None
+    }
+
+    fn environ_os(&self) -> Option<&[OsString]> {
+        None
+    }
+
     fn cwd(&self) -> &Path {
         self.cwd.as_path()
     }
@@ -309,9 +380,138 @@ impl ProcessExt for Process {
         self.start_time
     }
 
+    fn process_id(&self) -> ProcessId {
+        ProcessId {
+            pid: self.pid,
+            start_time: self.start_time(),
+            boot_id: 0,
+        }
+    }
+
     fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
+
+    fn disk_usage(&self) -> DiskUsage {
+        DiskUsage {
+            read_bytes: self.read_bytes.saturating_sub(self.old_read_bytes),
+            total_read_bytes: self.read_bytes,
+            written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
+            total_written_bytes: self.written_bytes,
+        }
+    }
+
+    fn cpu_time(&self) -> Option<CpuTime> {
+        Some(CpuTime {
+            total_user_time: Duration::from_nanos(self.user_time),
+            user_time: Duration::from_nanos(self.user_time.saturating_sub(self.old_user_time)),
+            total_system_time: Duration::from_nanos(self.system_time),
+            system_time: Duration::from_nanos(
+                self.system_time.saturating_sub(self.old_system_time),
+            ),
+        })
+    }
+
+    fn memory_breakdown(&self) -> Option<MemoryBreakdown> {
+        None
+    }
+
+    fn thread_count(&self) -> Option<u64> {
+        Some(self.thread_count)
+    }
+
+    fn activity(&self) -> Option<ProcessActivity> {
+        None
+    }
+
+    fn nice(&self) -> Option<i32> {
+        None
+    }
+
+    fn priority(&self) -> Option<i64> {
+        None
+    }
+
+    fn scheduling_policy(&self) -> Option<SchedulingPolicy> {
+        None
+    }
+
+    fn realtime_priority(&self) -> Option<u32> {
+        None
+    }
+
+    fn set_nice(&self, _nice: i32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn set_scheduling_policy(&self, _policy: SchedulingPolicy, _priority: u32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn cpu_affinity(&self) -> Option<Vec<usize>> {
+        None
+    }
+
+    fn set_cpu_affinity(&self, _processors: &[usize]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn user_id(&self) -> Option<Uid> {
+        Some(self.uid)
+    }
+
+    fn group_id(&self) -> Option<Gid> {
+        Some(self.gid)
+    }
+
+    fn real_user_id(&self) -> Option<Uid> {
+        Some(self.real_uid)
+    }
+
+    fn real_group_id(&self) -> Option<Gid> {
+        Some(self.real_gid)
+    }
+
+    fn threads(&self) -> &HashMap<Pid, Thread> {
+        &self.threads
+    }
+
+    fn open_files(&self) -> Option<Vec<OpenFile>> {
+        None
+    }
+
+    fn open_files_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn memory_details(&self) -> Option<MemoryDetails> {
+        None
+    }
+
+    fn memory_maps(&self) -> Option<Vec<MemoryMap>> {
+        None
+    }
+
+    fn resource_limits(&self) -> Option<Vec<ResourceLimit>> {
+        None
+    }
+
+    fn set_resource_limit(
+        &self,
+        _resource: Resource,
+        _soft: Option<Limit>,
+        _hard: Option<Limit>,
+    ) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn cgroups(&self) -> Option<Vec<Cgroup>> {
+        None
+    }
+
+    fn namespaces(&self) -> Option<Vec<Namespace>> {
+        None
+    }
 }
 
 pub(crate) fn compute_cpu_usage(p: &mut Process, time: u64, task_time: u64) {
@@ -335,6 +535,20 @@ pub(crate) fn compute_cpu_usage(p: &mut Process, time: u64, task_time: u64) {
     p.updated = true;
 }
 
+/// Updates the user and system times of the process from the (mach absolute time) values of
+/// `proc_taskinfo`.
+fn update_cpu_time(p: &mut Process, task_info: &libc::proc_taskinfo) {
+    let mut timebase = ffi::mach_timebase_info { numer: 1, denom: 1 };
+    unsafe {
+        ffi::mach_timebase_info(&mut timebase);
+    }
+    let to_nanos = |time: u64| time * timebase.numer as u64 / (timebase.denom as u64).max(1);
+    p.old_user_time = p.user_time;
+    p.old_system_time = p.system_time;
+    p.user_time = to_nanos(task_info.pti_total_user);
+    p.system_time = to_nanos(task_info.pti_total_system);
+}
+
 /*pub fn set_time(p: &mut Process, utime: u64, stime: u64) {
     p.old_utime = p.utime;
     p.old_stime = p.stime;
@@ -408,6 +622,9 @@ pub(crate) fn update_process(
 
             p.memory = task_info.pti_resident_size / 1_000;
             p.virtual_memory = task_info.pti_virtual_size / 1_000;
+            p.thread_count = task_info.pti_threadnum as u64;
+            update_cpu_time(p, &task_info);
+            update_proc_disk_activity(p);
             return Ok(None);
         }
 
@@ -590,14 +807,41 @@ pub(crate) fn update_process(
 
         p.memory = task_info.pti_resident_size / 1_000;
         p.virtual_memory = task_info.pti_virtual_size / 1_000;
+        p.thread_count = task_info.pti_threadnum as u64;
+        update_cpu_time(&mut p, &task_info);
 
         p.uid = info.pbi_uid;
         p.gid = info.pbi_gid;
+        p.real_uid = info.pbi_ruid;
+        p.real_gid = info.pbi_rgid;
         p.process_status = ProcessStatus::from(info.pbi_status);
+        update_proc_disk_activity(&mut p);
         Ok(Some(p))
     }
 }
 
+fn update_proc_disk_activity(p: &mut Process) {
+    p.old_read_bytes = p.read_bytes;
+    p.old_written_bytes = p.written_bytes;
+
+    let mut pidrusage = MaybeUninit::<ffi::RUsageInfoV2>::uninit();
+    let retval = unsafe {
+        ffi::proc_pid_rusage(
+            p.pid() as c_int,
+            ffi::RUSAGE_INFO_V2,
+            pidrusage.as_mut_ptr() as *mut c_void,
+        )
+    };
+
+    if retval < 0 {
+        sysinfo_debug!("proc_pid_rusage failed: {:?}", retval);
+    } else {
+        let pidrusage = unsafe { pidrusage.assume_init() };
+        p.read_bytes = pidrusage.ri_diskio_bytesread;
+        p.written_bytes = pidrusage.ri_diskio_byteswritten;
+    }
+}
+
 pub(crate) fn get_proc_list() -> Option<Vec<Pid>> {
     let count = unsafe { ffi::proc_listallpids(::std::ptr::null_mut(), 0) };
     if count < 1 {
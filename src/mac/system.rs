@@ -4,13 +4,15 @@
 // Copyright (c) 2015 Guillaume Gomez
 //
 
+use sys::block_device::BlockDevices;
 use sys::component::Component;
+use sys::disk::{self, Disk};
 use sys::ffi;
 use sys::network::Networks;
 use sys::process::*;
 use sys::processor::*;
 
-use {LoadAvg, Pid, ProcessExt, ProcessorExt, RefreshKind, SystemExt, User};
+use {LoadAvg, Pid, ProcessExt, ProcessorExt, RefreshKind, Socket, SystemExt, Uid, User, UserExt};
 
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
@@ -40,9 +42,12 @@ pub struct System {
     page_size_kb: u64,
     components: Vec<Component>,
     connection: Option<ffi::io_connect_t>,
+    disks: Vec<Disk>,
     networks: Networks,
+    block_devices: BlockDevices,
     port: ffi::mach_port_t,
     users: Vec<User>,
+    system_users: Vec<User>,
     boot_time: u64,
     session: SessionWrap,
 }
@@ -123,9 +128,12 @@ impl SystemExt for System {
             page_size_kb: unsafe { sysconf(_SC_PAGESIZE) as u64 / 1_000 },
             components: Vec::with_capacity(2),
             connection: get_io_service_connection(),
+            disks: Vec::with_capacity(1),
             networks: Networks::new(),
+            block_devices: BlockDevices::new(),
             port,
             users: Vec::new(),
+            system_users: Vec::new(),
             boot_time: boot_time(),
             session: SessionWrap(::std::ptr::null_mut()),
         };
@@ -294,8 +302,14 @@ impl SystemExt for System {
         }
     }
 
+    fn refresh_disks_list(&mut self) {
+        self.disks = disk::get_disks();
+    }
+
     fn refresh_users_list(&mut self) {
-        self.users = crate::mac::users::get_users_list();
+        let (users, system_users) = crate::mac::users::get_users_list();
+        self.users = users;
+        self.system_users = system_users;
     }
 
     // COMMON PART
@@ -326,6 +340,14 @@ impl SystemExt for System {
         &mut self.networks
     }
 
+    fn get_block_devices(&self) -> &BlockDevices {
+        &self.block_devices
+    }
+
+    fn get_block_devices_mut(&mut self) -> &mut BlockDevices {
+        &mut self.block_devices
+    }
+
     fn get_total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -363,6 +385,14 @@ impl SystemExt for System {
         &mut self.components
     }
 
+    fn get_disks(&self) -> &[Disk] {
+        &self.disks
+    }
+
+    fn get_disks_mut(&mut self) -> &mut [Disk] {
+        &mut self.disks
+    }
+
     fn get_uptime(&self) -> u64 {
         let csec = unsafe { libc::time(::std::ptr::null_mut()) };
 
@@ -385,6 +415,17 @@ impl SystemExt for System {
         &self.users
     }
 
+    fn get_user_by_id(&self, uid: Uid) -> Option<&User> {
+        self.users
+            .iter()
+            .chain(self.system_users.iter())
+            .find(|user| user.get_uid() == uid)
+    }
+
+    fn get_sockets(&self) -> Vec<Socket> {
+        Vec::new()
+    }
+
     fn get_boot_time(&self) -> u64 {
         self.boot_time
     }
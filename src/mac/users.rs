@@ -48,11 +48,12 @@ fn endswith(s1: *const c_char, s2: &[u8]) -> bool {
     i == -1
 }
 
-fn users_list<F>(filter: F) -> Vec<User>
+fn users_list<F>(filter: F) -> (Vec<User>, Vec<User>)
 where
     F: Fn(*const c_char) -> bool,
 {
     let mut users = Vec::new();
+    let mut system_users = Vec::new();
 
     unsafe { setpwent() };
     loop {
@@ -60,22 +61,33 @@ where
         if pw.is_null() {
             break;
         }
-        if !filter(unsafe { (*pw).pw_shell }) {
-            // This is not a "real" user.
-            continue;
-        }
         let groups = get_user_groups(unsafe { (*pw).pw_name }, unsafe { (*pw).pw_gid });
         if let Some(name) = utils::cstr_to_rust(unsafe { (*pw).pw_name }) {
-            users.push(User { name, groups });
+            let user = User {
+                name,
+                groups,
+                uid: unsafe { (*pw).pw_uid },
+                gid: unsafe { (*pw).pw_gid },
+            };
+            if filter(unsafe { (*pw).pw_shell }) {
+                users.push(user);
+            } else {
+                // This is not a "real" user.
+                system_users.push(user);
+            }
         }
     }
     unsafe { endpwent() };
-    users.sort_unstable_by(|x, y| x.name.partial_cmp(&y.name).unwrap());
-    users.dedup_by(|a, b| a.name == b.name);
-    users
+    for list in &mut [&mut users, &mut system_users] {
+        list.sort_unstable_by(|x, y| x.name.partial_cmp(&y.name).unwrap());
+        list.dedup_by(|a, b| a.name == b.name);
+    }
+    (users, system_users)
 }
 
-pub fn get_users_list() -> Vec<User> {
+/// Returns the "real" users and the system users (like daemon accounts), which are only used to
+/// resolve user ids.
+pub fn get_users_list() -> (Vec<User>, Vec<User>) {
     users_list(|shell| !endswith(shell, b"/false") && !endswith(shell, b"/uucico"))
 }
 
@@ -21,6 +21,8 @@ void        sysinfo_refresh_processes(CSystem system);
 #ifdef __linux__
 void        sysinfo_refresh_process(CSystem system, pid_t pid);
 #endif
+void        sysinfo_refresh_disks(CSystem system);
+void        sysinfo_refresh_disks_list(CSystem system);
 size_t      sysinfo_get_total_memory(CSystem system);
 size_t      sysinfo_get_free_memory(CSystem system);
 size_t      sysinfo_get_used_memory(CSystem system);
@@ -33,8 +35,8 @@ void        sysinfo_get_processors_usage(CSystem system, unsigned int *length, f
 size_t      sysinfo_get_processes(CSystem system, bool (*fn_pointer)(pid_t, CProcess, void*),
                                   void *data);
 #ifdef __linux__
-size_t      sysinfo_process_get_tasks(CProcess process, bool (*fn_pointer)(pid_t, CProcess, void*),
-                                      void *data);
+size_t      sysinfo_process_get_threads(CProcess process, bool (*fn_pointer)(pid_t, void*),
+                                        void *data);
 #endif
 CProcess    sysinfo_get_process_by_pid(CSystem system, pid_t pid);
 pid_t       sysinfo_process_get_pid(CProcess process);
@@ -29,6 +29,10 @@
 //!     println!("{:?}", component);
 //! }
 //!
+//! // And then all disks' information:
+//! for disk in system.get_disks() {
+//!     println!("{:?}", disk);
+//! }
 //!
 //! // And finally the RAM and SWAP information:
 //! println!("total memory: {} KB", system.get_total_memory());
@@ -55,6 +59,8 @@ extern crate rayon;
 extern crate doc_comment;
 
 extern crate once_cell;
+#[cfg(feature = "regex")]
+extern crate regex;
 
 #[cfg(doctest)]
 doctest!("../README.md");
@@ -105,11 +111,19 @@ cfg_if! {
 }
 
 pub use common::{
-    AsU32, LoadAvg, NetworksIter, Pid, RefreshKind, Signal, User,
+    AsU32, BlockDevicesIter, Cgroup, CpuTime, DiskKind, DiskUsage, FileKind, Gid, KillError, Limit,
+    LoadAvg, MemoryBreakdown, MemoryDetails, MemoryMap, Namespace, NamespaceKind, NetworksIter,
+    OpenFile, Pid, ProcessActivity, ProcessId, ProcessQuery, ProcessSort, RaidStatus, RefreshKind,
+    Resource, ResourceLimit, SchedulingPolicy, Signal, Socket, SocketProtocol, SocketState, Uid,
+    User,
+};
+pub use sys::{
+    BlockDevice, BlockDevices, Component, Disk, NetworkData, Networks, Process, ProcessHandle,
+    ProcessStatus, Processor, System, Thread,
 };
-pub use sys::{Component, NetworkData, Networks, Process, ProcessStatus, Processor, System};
 pub use traits::{
-    ComponentExt, NetworkExt, NetworksExt, ProcessExt, ProcessorExt, SystemExt, UserExt,
+    BlockDeviceExt, BlockDevicesExt, ComponentExt, DiskExt, NetworkExt, NetworksExt, ProcessExt,
+    ProcessHandleExt, ProcessorExt, SystemExt, ThreadExt, UserExt,
 };
 
 #[cfg(feature = "c-interface")]
@@ -4,18 +4,226 @@
 // Copyright (c) 2017 Guillaume Gomez
 //
 
-use sys::{Component, Networks, Process, Processor};
+use common::RankedProcess;
+use sys::{
+    BlockDevice, BlockDevices, Component, Disk, Networks, Process, ProcessHandle, Processor, Thread,
+};
+use BlockDevicesIter;
+use Cgroup;
+use CpuTime;
+use DiskKind;
+use DiskUsage;
+use Gid;
+use KillError;
+use Limit;
 use LoadAvg;
+use MemoryBreakdown;
+use MemoryDetails;
+use MemoryMap;
+use Namespace;
 use NetworksIter;
+use OpenFile;
 use Pid;
+use ProcessActivity;
+use ProcessId;
+use ProcessQuery;
+use ProcessSort;
 use ProcessStatus;
+use RaidStatus;
 use RefreshKind;
+use Resource;
+use ResourceLimit;
+use SchedulingPolicy;
+use Socket;
+use Uid;
 use User;
 
-use std::collections::HashMap;
-use std::ffi::OsStr;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
 use std::fmt::Debug;
+use std::io;
 use std::path::Path;
+use std::time::Duration;
+
+/// Contains all the methods of the [`Disk`][crate::Disk] struct.
+///
+/// ```no_run
+/// use sysinfo::{DiskExt, System, SystemExt};
+///
+/// let s = System::new();
+/// for disk in s.get_disks() {
+///     println!("{:?}: {} B available", disk.get_name(), disk.get_available_space());
+/// }
+/// ```
+pub trait DiskExt: Debug {
+    /// Returns the disk kind.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{:?}", disk.get_kind());
+    /// }
+    /// ```
+    fn get_kind(&self) -> DiskKind;
+
+    /// Returns the disk name.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{:?}", disk.get_name());
+    /// }
+    /// ```
+    fn get_name(&self) -> &OsStr;
+
+    /// Returns the file system used on this disk (so for example: `EXT4`, `NTFS`, etc...).
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{:?}", disk.get_file_system());
+    /// }
+    /// ```
+    fn get_file_system(&self) -> &[u8];
+
+    /// Returns the mount point of the disk (`/` for example).
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{:?}", disk.get_mount_point());
+    /// }
+    /// ```
+    fn get_mount_point(&self) -> &Path;
+
+    /// Returns the total disk size, in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{}", disk.get_total_space());
+    /// }
+    /// ```
+    fn get_total_space(&self) -> u64;
+
+    /// Returns the available disk size, in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{}", disk.get_available_space());
+    /// }
+    /// ```
+    fn get_available_space(&self) -> u64;
+
+    /// Returns the total number of inodes of the disk.
+    ///
+    /// It returns `0` on file systems which don't have a fixed number of inodes or on systems
+    /// where there is no such concept (like Windows).
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{}", disk.get_total_inodes());
+    /// }
+    /// ```
+    fn get_total_inodes(&self) -> u64;
+
+    /// Returns the number of free inodes of the disk.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{}", disk.get_free_inodes());
+    /// }
+    /// ```
+    fn get_free_inodes(&self) -> u64;
+
+    /// Returns the options the disk is mounted with (like `ro`, `noexec` or `nosuid`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("{}", disk.get_mount_options().join(","));
+    /// }
+    /// ```
+    fn get_mount_options(&self) -> &[String];
+
+    /// Returns `true` if the disk is mounted with the given option.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("noexec: {}", disk.has_mount_option("noexec"));
+    /// }
+    /// ```
+    fn has_mount_option(&self, option: &str) -> bool {
+        self.get_mount_options().iter().any(|o| o == option)
+    }
+
+    /// Returns `true` if the disk is mounted read-only.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     println!("read-only: {}", disk.is_read_only());
+    /// }
+    /// ```
+    fn is_read_only(&self) -> bool {
+        self.has_mount_option("ro")
+    }
+
+    /// Returns the major and minor numbers of the disk's device.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for disk in s.get_disks() {
+    ///     let (major, minor) = disk.get_device_id();
+    ///     println!("{}:{}", major, minor);
+    /// }
+    /// ```
+    fn get_device_id(&self) -> (u32, u32);
+
+    /// Updates the disk's information.
+    ///
+    /// The space and inodes usage (the file system might have been resized) and whether the
+    /// disk is read-only (it might have been remounted) are updated.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// for disk in s.get_disks_mut() {
+    ///     disk.refresh();
+    /// }
+    /// ```
+    fn refresh(&mut self) -> bool;
+}
 
 /// Contains all the methods of the [`Process`][crate::Process] struct.
 pub trait ProcessExt: Debug {
@@ -35,7 +243,43 @@ pub trait ProcessExt: Debug {
     ///     process.kill(Signal::Kill);
     /// }
     /// ```
-    fn kill(&self, signal: ::Signal) -> bool;
+    fn kill(&self, signal: ::Signal) -> bool {
+        self.try_kill(signal).is_ok()
+    }
+
+    /// Sends the given `signal` to the process. Unlike [`ProcessExt::kill`], it returns why the
+    /// signal couldn't be sent.
+    ///
+    /// ```no_run
+    /// use sysinfo::{KillError, ProcessExt, Signal, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Err(KillError::PermissionDenied) = process.try_kill(Signal::Kill) {
+    ///         eprintln!("not allowed to kill this process");
+    ///     }
+    /// }
+    /// ```
+    fn try_kill(&self, signal: ::Signal) -> Result<(), KillError>;
+
+    /// Returns a [`ProcessHandle`][crate::ProcessHandle] referring to this process. Unlike the
+    /// pid, it cannot refer to another process if the pid is reused once this one exited.
+    ///
+    /// It fails if the process already exited (and its pid was reused) or if it isn't supported
+    /// (only Linux provides it for the moment).
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessHandleExt, Signal, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Ok(handle) = process.handle() {
+    ///         // No matter how long it takes, the signal won't be sent to another process.
+    ///         let _ = handle.try_kill(Signal::Term);
+    ///     }
+    /// }
+    /// ```
+    fn handle(&self) -> io::Result<ProcessHandle>;
 
     /// Returns the name of the process.
     ///
@@ -99,6 +343,81 @@ pub trait ProcessExt: Debug {
     /// ```
     fn environ(&self) -> &[String];
 
+    /// Returns the command line without converting it to UTF-8, so arguments which aren't valid
+    /// UTF-8 are kept as is.
+    ///
+    /// It returns `None` if the information isn't available (only Linux provides it for the
+    /// moment), in which case [`ProcessExt::cmd`] should be used.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.cmd_os());
+    /// }
+    /// ```
+    fn cmd_os(&self) -> Option<&[OsString]>;
+
+    /// Returns the environment of the process without converting it to UTF-8.
+    ///
+    /// It returns `None` if the information isn't available (only Linux provides it for the
+    /// moment), in which case [`ProcessExt::environ`] should be used.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.environ_os());
+    /// }
+    /// ```
+    fn environ_os(&self) -> Option<&[OsString]>;
+
+    /// Returns the environment of the process as a map of variable names to their values.
+    /// Entries of [`ProcessExt::environ`] without `=` are ignored.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for (name, value) in process.environ_map() {
+    ///         println!("{}={}", name, value);
+    ///     }
+    /// }
+    /// ```
+    fn environ_map(&self) -> HashMap<&str, &str> {
+        self.environ()
+            .iter()
+            .filter_map(|var| {
+                let mut parts = var.splitn(2, '=');
+                Some((parts.next()?, parts.next()?))
+            })
+            .collect()
+    }
+
+    /// Returns the value of the environment variable `name` of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.environ_var("PATH"));
+    /// }
+    /// ```
+    fn environ_var(&self, name: &str) -> Option<&str> {
+        self.environ().iter().find_map(|var| {
+            let mut parts = var.splitn(2, '=');
+            if parts.next()? == name {
+                parts.next()
+            } else {
+                None
+            }
+        })
+    }
+
     /// Returns the current working directory.
     ///
     /// Always empty on Windows.
@@ -187,6 +506,19 @@ pub trait ProcessExt: Debug {
     /// ```
     fn start_time(&self) -> u64;
 
+    /// Returns an identifier of the process which, unlike its pid, cannot be reused by another
+    /// process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.process_id());
+    /// }
+    /// ```
+    fn process_id(&self) -> ProcessId;
+
     /// Returns the total CPU usage (in %).
     ///
     /// ```no_run
@@ -198,755 +530,2268 @@ pub trait ProcessExt: Debug {
     /// }
     /// ```
     fn cpu_usage(&self) -> f32;
-}
 
-/// Contains all the methods of the [`Processor`][crate::Processor] struct.
-pub trait ProcessorExt: Debug {
-    /// Returns this processor's usage.
-    ///
-    /// Note: You'll need to refresh it at least twice (diff between the first and the second is
-    /// how CPU usage is computed) at first if you want to have a non-zero value.
+    /// Returns number of bytes read and written to disk.
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new();
-    /// for processor in s.get_processors() {
-    ///     println!("{}%", processor.get_cpu_usage());
+    /// if let Some(process) = s.get_process(1337) {
+    ///     let disk_usage = process.disk_usage();
+    ///     println!("read bytes   : new/total => {}/{}",
+    ///         disk_usage.read_bytes,
+    ///         disk_usage.total_read_bytes,
+    ///     );
+    ///     println!("written bytes: new/total => {}/{}",
+    ///         disk_usage.written_bytes,
+    ///         disk_usage.total_written_bytes,
+    ///     );
     /// }
     /// ```
-    fn get_cpu_usage(&self) -> f32;
+    fn disk_usage(&self) -> DiskUsage;
 
-    /// Returns this processor's name.
+    /// Returns the CPU time consumed by the process in user and kernel modes, both in total
+    /// and since the last refresh.
+    ///
+    /// It returns `None` if the information isn't available.
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new();
-    /// for processor in s.get_processors() {
-    ///     println!("{}", processor.get_name());
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Some(cpu_time) = process.cpu_time() {
+    ///         println!("user: {:?}, system: {:?}",
+    ///             cpu_time.total_user_time,
+    ///             cpu_time.total_system_time,
+    ///         );
+    ///     }
     /// }
     /// ```
-    fn get_name(&self) -> &str;
+    fn cpu_time(&self) -> Option<CpuTime>;
 
-    /// Returns the processor's vendor id.
+    /// Returns the breakdown of the memory usage of the process (anonymous, file-backed and
+    /// shared memory, swap, peaks, etc).
+    ///
+    /// It returns `None` if the information isn't available (only Linux provides it for the
+    /// moment).
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new();
-    /// for processor in s.get_processors() {
-    ///     println!("{}", processor.get_vendor_id());
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Some(breakdown) = process.memory_breakdown() {
+    ///         println!("peak memory: {} kB", breakdown.peak_memory);
+    ///     }
     /// }
     /// ```
-    fn get_vendor_id(&self) -> &str;
+    fn memory_breakdown(&self) -> Option<MemoryBreakdown>;
 
-    /// Returns the processor's brand.
+    /// Returns the number of threads of the process.
+    ///
+    /// It returns `None` if the information isn't available.
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new();
-    /// for processor in s.get_processors() {
-    ///     println!("{}", processor.get_brand());
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.thread_count());
     /// }
     /// ```
-    fn get_brand(&self) -> &str;
+    fn thread_count(&self) -> Option<u64>;
 
-    /// Returns the processor's frequency.
+    /// Returns the page faults, the time spent by the waited-for children and the context
+    /// switches of the process, both in total and since the last refresh.
+    ///
+    /// It returns `None` if the information isn't available (only Linux provides it for the
+    /// moment).
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new();
-    /// for processor in s.get_processors() {
-    ///     println!("{}", processor.get_frequency());
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Some(activity) = process.activity() {
+    ///         println!("{} major faults since last refresh", activity.major_faults);
+    ///     }
     /// }
     /// ```
-    fn get_frequency(&self) -> u64;
-}
+    fn activity(&self) -> Option<ProcessActivity>;
 
-/// Contains all the methods of the [`System`][crate::System] type.
-pub trait SystemExt: Sized + Debug + Default {
-    /// Creates a new [`System`] instance with nothing loaded except the processors list. If you
-    /// want to load components, network interfaces, you'll have to use the
-    /// `refresh_*_list` methods. [`SystemExt::refresh_networks_list`] for example.
+    /// Returns the nice value of the process, from `-20` (highest priority) to `19` (lowest
+    /// priority).
     ///
-    /// Use the [`refresh_all`] method to update its internal information (or any of the `refresh_`
-    /// method).
-    ///
-    /// [`System`]: crate::System
-    /// [`refresh_all`]: #method.refresh_all
+    /// It returns `None` if the information isn't available (only Linux provides it for the
+    /// moment).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.nice());
+    /// }
     /// ```
-    fn new() -> Self {
-        Self::new_with_specifics(RefreshKind::new())
-    }
+    fn nice(&self) -> Option<i32>;
 
-    /// Creates a new [`System`] instance with everything loaded.
-    ///
-    /// It is an equivalent of [`SystemExt::new_with_specifics`]`(`[`RefreshKind::everything`]`())`.
+    /// Returns the priority of the process as seen by the kernel. For time-sharing processes, it
+    /// is the nice value plus `20`. For real-time processes, it is the opposite of the real-time
+    /// priority minus one.
     ///
-    /// [`System`]: crate::System
+    /// It returns `None` if the information isn't available.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let s = System::new_all();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.priority());
+    /// }
     /// ```
-    fn new_all() -> Self {
-        Self::new_with_specifics(RefreshKind::everything())
-    }
+    fn priority(&self) -> Option<i64>;
 
-    /// Creates a new [`System`] instance and refresh the data corresponding to the
-    /// given [`RefreshKind`].
+    /// Returns the scheduling policy of the process.
     ///
-    /// [`System`]: crate::System
+    /// It returns `None` if the information isn't available.
     ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.scheduling_policy());
+    /// }
     /// ```
-    /// use sysinfo::{RefreshKind, System, SystemExt};
+    fn scheduling_policy(&self) -> Option<SchedulingPolicy>;
+
+    /// Returns the real-time priority of the process, from `1` to `99`. It is `0` for processes
+    /// which don't have a real-time scheduling policy.
     ///
-    /// assert!(system.get_processes().len() > 0);
+    /// It returns `None` if the information isn't available.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.realtime_priority());
+    /// }
     /// ```
-    fn new_with_specifics(refreshes: RefreshKind) -> Self;
+    fn realtime_priority(&self) -> Option<u32>;
 
-    /// Refreshes according to the given [`RefreshKind`]. It calls the corresponding
-    /// "refresh_" methods.
+    /// Sets the nice value of the process. Only a privileged process can decrease it.
     ///
-    /// ```
-    /// use sysinfo::{RefreshKind, System, SystemExt};
+    /// The new value will only be returned by [`ProcessExt::nice`] after the process has been
+    /// refreshed.
     ///
-    /// let mut s = System::new_all();
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// // Let's just update networks and processes:
-    /// s.refresh_specifics(RefreshKind::new().with_networks().with_processes());
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Err(e) = process.set_nice(10) {
+    ///         eprintln!("failed to update nice value: {}", e);
+    ///     }
+    /// }
     /// ```
-    fn refresh_specifics(&mut self, refreshes: RefreshKind) {
-        if refreshes.memory() {
-            self.refresh_memory();
-        }
-        if refreshes.cpu() {
-            self.refresh_cpu();
-        }
-        if refreshes.components_list() {
-            self.refresh_components_list();
-        } else if refreshes.components() {
-            self.refresh_components();
-        }
-        if refreshes.networks_list() {
-            self.refresh_networks_list();
-        } else if refreshes.networks() {
-            self.refresh_networks();
-        }
-        if refreshes.processes() {
-            self.refresh_processes();
-        }
-        if refreshes.users_list() {
-            self.refresh_users_list();
-        }
-    }
+    fn set_nice(&self, nice: i32) -> io::Result<()>;
 
-    /// Refreshes system information (RAM, swap, CPU usage and components' temperature).
+    /// Sets the scheduling policy of the process, with the given real-time priority. The
+    /// priority must be `0` for non real-time policies. Only a privileged process can set a
+    /// real-time policy.
     ///
-    /// If you want some more specific refreshes, you might be interested into looking at
-    /// [`refresh_memory`], [`refresh_cpu`] and [`refresh_components`].
+    /// [`SchedulingPolicy::Deadline`] can't be set with this method.
     ///
-    /// [`refresh_memory`]: SystemExt::refresh_memory
-    /// [`refresh_cpu`]: SystemExt::refresh_memory
-    /// [`refresh_components`]: SystemExt::refresh_components
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, SchedulingPolicy, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Err(e) = process.set_scheduling_policy(SchedulingPolicy::Batch, 0) {
+    ///         eprintln!("failed to update scheduling policy: {}", e);
+    ///     }
+    /// }
+    /// ```
+    fn set_scheduling_policy(&self, policy: SchedulingPolicy, priority: u32) -> io::Result<()>;
+
+    /// Returns the processors the process is allowed to run on. They are indices of the list
+    /// returned by [`SystemExt::get_processors`].
+    ///
+    /// The information isn't stored in the process but read when this method is called. It
+    /// returns `None` if the information isn't available (only Linux provides it for the
+    /// moment).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_system();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.cpu_affinity());
+    /// }
     /// ```
-    fn refresh_system(&mut self) {
-        self.refresh_memory();
-        self.refresh_cpu();
-        self.refresh_components();
-    }
+    fn cpu_affinity(&self) -> Option<Vec<usize>>;
 
-    /// Refreshes RAM and SWAP usage.
+    /// Restricts the process to run on the given processors. They are indices of the list
+    /// returned by [`SystemExt::get_processors`].
+    ///
+    /// Only the main thread of the process is affected, use [`ThreadExt::set_cpu_affinity`] to
+    /// pin the other ones.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_memory();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Err(e) = process.set_cpu_affinity(&[0, 1]) {
+    ///         eprintln!("failed to pin process: {}", e);
+    ///     }
+    /// }
     /// ```
-    fn refresh_memory(&mut self);
+    fn set_cpu_affinity(&self, processors: &[usize]) -> io::Result<()>;
 
-    /// Refreshes CPU usage.
+    /// Returns the effective user id of the process.
+    ///
+    /// It returns `None` if the information isn't available (on Windows for example).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_cpu();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.user_id());
+    /// }
     /// ```
-    fn refresh_cpu(&mut self);
+    fn user_id(&self) -> Option<Uid>;
 
-    /// Refreshes components' temperature.
+    /// Returns the effective group id of the process.
+    ///
+    /// It returns `None` if the information isn't available (on Windows for example).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_components();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.group_id());
+    /// }
     /// ```
-    fn refresh_components(&mut self) {
-        for component in self.get_components_mut() {
-            component.refresh();
-        }
-    }
+    fn group_id(&self) -> Option<Gid>;
 
-    /// Refreshes components list.
+    /// Returns the real user id of the process, which is the id of the user who started it.
+    ///
+    /// It only differs from [`ProcessExt::user_id`] when the process changed its effective user
+    /// id (a setuid binary for example).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new();
-    /// s.refresh_components_list();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("real: {:?}, effective: {:?}", process.real_user_id(), process.user_id());
+    /// }
     /// ```
-    fn refresh_components_list(&mut self);
+    fn real_user_id(&self) -> Option<Uid>;
 
-    /// Gets all processes and updates their information.
+    /// Returns the real group id of the process.
+    ///
+    /// It only differs from [`ProcessExt::group_id`] when the process changed its effective
+    /// group id (a setgid binary for example).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_processes();
+    /// let s = System::new();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("real: {:?}, effective: {:?}", process.real_group_id(), process.group_id());
+    /// }
     /// ```
-    fn refresh_processes(&mut self);
+    fn real_group_id(&self) -> Option<Gid>;
 
-    /// Refreshes *only* the process corresponding to `pid`. Returns `false` if the process doesn't
-    /// exist. If it isn't listed yet, it'll be added.
+    /// Returns the threads of the process, indexed by their thread id.
+    ///
+    /// It is only filled on Linux for the moment.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_process(1337);
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for (tid, thread) in process.threads() {
+    ///         println!("{} {}: {}%", tid, thread.name(), thread.cpu_usage());
+    ///     }
+    /// }
     /// ```
-    fn refresh_process(&mut self, pid: Pid) -> bool;
+    fn threads(&self) -> &HashMap<Pid, Thread>;
 
-    /// Refreshes users list.
+    /// Returns the file descriptors opened by the process.
+    ///
+    /// The information isn't stored in the process but read when this method is called. It
+    /// returns `None` if the information isn't available, either because the platform isn't
+    /// supported (only Linux is for the moment) or because of missing rights.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_users_list();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for file in process.open_files().unwrap_or_default() {
+    ///         println!("{} => {:?}", file.fd, file.target);
+    ///     }
+    /// }
     /// ```
-    fn refresh_users_list(&mut self);
+    fn open_files(&self) -> Option<Vec<OpenFile>>;
 
-    /// Refreshes networks data.
+    /// Returns the number of file descriptors opened by the process.
+    ///
+    /// It is much cheaper than [`ProcessExt::open_files`] since the file descriptors aren't
+    /// inspected. Just like it, it returns `None` if the information isn't available.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_networks();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{:?}", process.open_files_count());
+    /// }
     /// ```
+    fn open_files_count(&self) -> Option<usize>;
+
+    /// Returns the detailed memory usage of the process, including how much of it is shared
+    /// with other processes.
     ///
-    /// It is a shortcut for:
+    /// The information isn't stored in the process but read when this method is called since it
+    /// can be slow to compute. It returns `None` if the information isn't available, either
+    /// because the platform isn't supported (only Linux is for the moment) or because of missing
+    /// rights.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworksExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// let networks = s.get_networks_mut();
-    /// networks.refresh();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     if let Some(details) = process.memory_details() {
+    ///         println!("{} kB", details.pss);
+    ///     }
+    /// }
     /// ```
-    fn refresh_networks(&mut self) {
-        self.get_networks_mut().refresh();
-    }
+    fn memory_details(&self) -> Option<MemoryDetails>;
 
-    /// The network list will be updated: removing not existing anymore interfaces and adding new
-    /// ones.
+    /// Returns the memory mappings of the process.
+    ///
+    /// Just like [`ProcessExt::memory_details`], it is read when this method is called and
+    /// returns `None` if the information isn't available.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_networks_list();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for map in process.memory_maps().unwrap_or_default() {
+    ///         println!("{:?}: {} kB", map.path, map.rss);
+    ///     }
+    /// }
     /// ```
+    fn memory_maps(&self) -> Option<Vec<MemoryMap>>;
+
+    /// Returns the resource limits of the process.
     ///
-    /// This is a shortcut for:
+    /// The information isn't stored in the process but read when this method is called. It
+    /// returns `None` if the information isn't available, either because the platform isn't
+    /// supported (only Linux is for the moment) or because of missing rights.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworksExt, System, SystemExt};
+    /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// let networks = s.get_networks_mut();
-    /// networks.refresh_networks_list();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for limit in process.resource_limits().unwrap_or_default() {
+    ///         println!("{:?}: {:?}/{:?}", limit.resource, limit.soft, limit.hard);
+    ///     }
+    /// }
     /// ```
-    fn refresh_networks_list(&mut self) {
-        self.get_networks_mut().refresh_networks_list();
-    }
+    fn resource_limits(&self) -> Option<Vec<ResourceLimit>>;
 
-    /// Refreshes all system, processes, and network interfaces information.
+    /// Sets the soft and hard limits of the given resource for the process. A `None` limit keeps
+    /// its current value, use [`Limit::Unlimited`] to remove it.
     ///
-    /// Please note that it doesn't recompute components list, network interfaces
-    /// list nor users list.
+    /// Only a privileged process can raise the hard limit or change the limits of processes
+    /// owned by another user. It always returns an error on non-linux targets.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{Limit, ProcessExt, Resource, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// s.refresh_all();
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     // Only the soft limit is changed.
+    ///     let soft = Some(Limit::Value(4096));
+    ///     if let Err(e) = process.set_resource_limit(Resource::OpenFiles, soft, None) {
+    ///         eprintln!("failed to update the open files limit: {}", e);
+    ///     }
+    /// }
     /// ```
-    fn refresh_all(&mut self) {
-        self.refresh_system();
-        self.refresh_processes();
-        self.refresh_networks();
-    }
+    fn set_resource_limit(
+        &self,
+        resource: Resource,
+        soft: Option<Limit>,
+        hard: Option<Limit>,
+    ) -> io::Result<()>;
 
-    /// Returns the process list.
+    /// Returns the cgroups the process belongs to: one for the unified (v2) hierarchy and one for
+    /// each cgroup v1 hierarchy.
+    ///
+    /// The information isn't stored in the process but read when this method is called. It
+    /// returns `None` if the information isn't available (only Linux provides it).
     ///
     /// ```no_run
     /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// for (pid, process) in s.get_processes() {
-    ///     println!("{} {}", pid, process.name());
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for cgroup in process.cgroups().unwrap_or_default() {
+    ///         println!("{:?}: {:?}", cgroup.controllers, cgroup.path);
+    ///     }
     /// }
     /// ```
-    fn get_processes(&self) -> &HashMap<Pid, Process>;
+    fn cgroups(&self) -> Option<Vec<Cgroup>>;
 
-    /// Returns the process corresponding to the given pid or `None` if no such process exists.
+    /// Returns the namespaces the process belongs to.
+    ///
+    /// The information isn't stored in the process but read when this method is called. It
+    /// returns `None` if the information isn't available, either because the platform isn't
+    /// supported (only Linux is) or because of missing rights.
     ///
     /// ```no_run
     /// use sysinfo::{ProcessExt, System, SystemExt};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.get_process(1337) {
-    ///     println!("{}", process.name());
+    ///     for namespace in process.namespaces().unwrap_or_default() {
+    ///         println!("{:?}: {}", namespace.kind, namespace.id);
+    ///     }
+    /// }
+    /// ```
+    fn namespaces(&self) -> Option<Vec<Namespace>>;
+}
+
+/// Contains all the methods of the [`ProcessHandle`][crate::ProcessHandle] struct.
+pub trait ProcessHandleExt: Debug {
+    /// Returns the pid of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessHandleExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(handle) = s.get_process(1337).and_then(|p| p.handle().ok()) {
+    ///     println!("{}", handle.pid());
+    /// }
+    /// ```
+    fn pid(&self) -> Pid;
+
+    /// Sends the given `signal` to the process. If the process exited, it returns
+    /// [`KillError::NoSuchProcess`][crate::KillError::NoSuchProcess], even if its pid has been
+    /// reused.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessHandleExt, Signal, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(handle) = s.get_process(1337).and_then(|p| p.handle().ok()) {
+    ///     if let Err(e) = handle.try_kill(Signal::Kill) {
+    ///         eprintln!("failed to kill process: {}", e);
+    ///     }
+    /// }
+    /// ```
+    fn try_kill(&self, signal: ::Signal) -> Result<(), KillError>;
+
+    /// Returns `true` if the process exited. It doesn't block.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessHandleExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(handle) = s.get_process(1337).and_then(|p| p.handle().ok()) {
+    ///     println!("exited: {}", handle.has_exited());
+    /// }
+    /// ```
+    fn has_exited(&self) -> bool;
+
+    /// Blocks until the process exits.
+    ///
+    /// Unlike `waitpid`, it works for processes which aren't children of the current one but it
+    /// doesn't reap them.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessHandleExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(handle) = s.get_process(1337).and_then(|p| p.handle().ok()) {
+    ///     handle.wait().expect("failed to wait for process");
+    /// }
+    /// ```
+    fn wait(&self) -> io::Result<()>;
+
+    /// Blocks until the process exits or until `timeout` elapsed. Returns `true` if the process
+    /// exited.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::{ProcessExt, ProcessHandleExt, Signal, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// if let Some(handle) = s.get_process(1337).and_then(|p| p.handle().ok()) {
+    ///     let _ = handle.try_kill(Signal::Term);
+    ///     if !handle.wait_timeout(Duration::from_secs(5)).unwrap_or(false) {
+    ///         let _ = handle.try_kill(Signal::Kill);
+    ///     }
+    /// }
+    /// ```
+    fn wait_timeout(&self, timeout: Duration) -> io::Result<bool>;
+}
+
+/// Contains all the methods of the [`Thread`][crate::Thread] struct.
+pub trait ThreadExt: Debug {
+    /// Returns the thread id.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{}", thread.tid());
+    ///     }
+    /// }
+    /// ```
+    fn tid(&self) -> Pid;
+
+    /// Returns the name of the thread.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{}", thread.name());
+    ///     }
+    /// }
+    /// ```
+    fn name(&self) -> &str;
+
+    /// Returns the status of the thread.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{:?}", thread.status());
+    ///     }
+    /// }
+    /// ```
+    fn status(&self) -> ProcessStatus;
+
+    /// Returns the total CPU usage (in %) of the thread. Just like for processes, it can be
+    /// bigger than 100% if the machine has more than one processor.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{}%", thread.cpu_usage());
+    ///     }
+    /// }
+    /// ```
+    fn cpu_usage(&self) -> f32;
+
+    /// Returns the number of times the thread gave up the processor by itself (waiting for a
+    /// resource for example).
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{}", thread.voluntary_context_switches());
+    ///     }
+    /// }
+    /// ```
+    fn voluntary_context_switches(&self) -> u64;
+
+    /// Returns the number of times the thread was forced to give up the processor (because its
+    /// time slice was over for example).
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{}", thread.involuntary_context_switches());
+    ///     }
+    /// }
+    /// ```
+    fn involuntary_context_switches(&self) -> u64;
+
+    /// Returns the processors the thread is allowed to run on. They are indices of the list
+    /// returned by [`SystemExt::get_processors`].
+    ///
+    /// It is read when this method is called and returns `None` if the information isn't
+    /// available.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for thread in process.threads().values() {
+    ///         println!("{}: {:?}", thread.name(), thread.cpu_affinity());
+    ///     }
+    /// }
+    /// ```
+    fn cpu_affinity(&self) -> Option<Vec<usize>>;
+
+    /// Restricts the thread to run on the given processors. They are indices of the list
+    /// returned by [`SystemExt::get_processors`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, ThreadExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     for (i, thread) in process.threads().values().enumerate() {
+    ///         if let Err(e) = thread.set_cpu_affinity(&[i % s.get_processors().len()]) {
+    ///             eprintln!("failed to pin thread: {}", e);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn set_cpu_affinity(&self, processors: &[usize]) -> io::Result<()>;
+}
+
+/// Contains all the methods of the [`Processor`][crate::Processor] struct.
+pub trait ProcessorExt: Debug {
+    /// Returns this processor's usage.
+    ///
+    /// Note: You'll need to refresh it at least twice (diff between the first and the second is
+    /// how CPU usage is computed) at first if you want to have a non-zero value.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for processor in s.get_processors() {
+    ///     println!("{}%", processor.get_cpu_usage());
+    /// }
+    /// ```
+    fn get_cpu_usage(&self) -> f32;
+
+    /// Returns this processor's name.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for processor in s.get_processors() {
+    ///     println!("{}", processor.get_name());
+    /// }
+    /// ```
+    fn get_name(&self) -> &str;
+
+    /// Returns the processor's vendor id.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for processor in s.get_processors() {
+    ///     println!("{}", processor.get_vendor_id());
+    /// }
+    /// ```
+    fn get_vendor_id(&self) -> &str;
+
+    /// Returns the processor's brand.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for processor in s.get_processors() {
+    ///     println!("{}", processor.get_brand());
+    /// }
+    /// ```
+    fn get_brand(&self) -> &str;
+
+    /// Returns the processor's frequency.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for processor in s.get_processors() {
+    ///     println!("{}", processor.get_frequency());
+    /// }
+    /// ```
+    fn get_frequency(&self) -> u64;
+}
+
+/// Contains all the methods of the [`System`][crate::System] type.
+pub trait SystemExt: Sized + Debug + Default {
+    /// Creates a new [`System`] instance with nothing loaded except the processors list. If you
+    /// want to load components, network interfaces, you'll have to use the
+    /// `refresh_*_list` methods. [`SystemExt::refresh_networks_list`] for example.
+    ///
+    /// Use the [`refresh_all`] method to update its internal information (or any of the `refresh_`
+    /// method).
+    ///
+    /// [`System`]: crate::System
+    /// [`refresh_all`]: #method.refresh_all
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// ```
+    fn new() -> Self {
+        Self::new_with_specifics(RefreshKind::new())
+    }
+
+    /// Creates a new [`System`] instance with everything loaded.
+    ///
+    /// It is an equivalent of [`SystemExt::new_with_specifics`]`(`[`RefreshKind::everything`]`())`.
+    ///
+    /// [`System`]: crate::System
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// ```
+    fn new_all() -> Self {
+        Self::new_with_specifics(RefreshKind::everything())
+    }
+
+    /// Creates a new [`System`] instance and refresh the data corresponding to the
+    /// given [`RefreshKind`].
+    ///
+    /// [`System`]: crate::System
+    ///
+    /// ```
+    /// use sysinfo::{RefreshKind, System, SystemExt};
+    ///
+    /// // We want everything except disks.
+    /// let mut system = System::new_with_specifics(RefreshKind::everything().without_disks_list());
+    ///
+    /// assert_eq!(system.get_disks().len(), 0);
+    /// assert!(system.get_processes().len() > 0);
+    /// ```
+    fn new_with_specifics(refreshes: RefreshKind) -> Self;
+
+    /// Refreshes according to the given [`RefreshKind`]. It calls the corresponding
+    /// "refresh_" methods.
+    ///
+    /// ```
+    /// use sysinfo::{RefreshKind, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// // Let's just update networks and processes:
+    /// s.refresh_specifics(RefreshKind::new().with_networks().with_processes());
+    /// ```
+    fn refresh_specifics(&mut self, refreshes: RefreshKind) {
+        if refreshes.memory() {
+            self.refresh_memory();
+        }
+        if refreshes.cpu() {
+            self.refresh_cpu();
+        }
+        if refreshes.components_list() {
+            self.refresh_components_list();
+        } else if refreshes.components() {
+            self.refresh_components();
+        }
+        if refreshes.networks_list() {
+            self.refresh_networks_list();
+        } else if refreshes.networks() {
+            self.refresh_networks();
+        }
+        if refreshes.block_devices_list() {
+            self.refresh_block_devices_list();
+        } else if refreshes.block_devices() {
+            self.refresh_block_devices();
+        }
+        if refreshes.processes() {
+            self.refresh_processes();
+        }
+        if refreshes.disks_list() {
+            self.refresh_disks_list();
+        } else if refreshes.disks() {
+            self.refresh_disks();
+        }
+        if refreshes.users_list() {
+            self.refresh_users_list();
+        }
+    }
+
+    /// Refreshes system information (RAM, swap, CPU usage and components' temperature).
+    ///
+    /// If you want some more specific refreshes, you might be interested into looking at
+    /// [`refresh_memory`], [`refresh_cpu`] and [`refresh_components`].
+    ///
+    /// [`refresh_memory`]: SystemExt::refresh_memory
+    /// [`refresh_cpu`]: SystemExt::refresh_memory
+    /// [`refresh_components`]: SystemExt::refresh_components
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_system();
+    /// ```
+    fn refresh_system(&mut self) {
+        self.refresh_memory();
+        self.refresh_cpu();
+        self.refresh_components();
+    }
+
+    /// Refreshes RAM and SWAP usage.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_memory();
+    /// ```
+    fn refresh_memory(&mut self);
+
+    /// Refreshes CPU usage.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_cpu();
+    /// ```
+    fn refresh_cpu(&mut self);
+
+    /// Refreshes components' temperature.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_components();
+    /// ```
+    fn refresh_components(&mut self) {
+        for component in self.get_components_mut() {
+            component.refresh();
+        }
+    }
+
+    /// Refreshes components list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_components_list();
+    /// ```
+    fn refresh_components_list(&mut self);
+
+    /// Gets all processes and updates their information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_processes();
+    /// ```
+    fn refresh_processes(&mut self);
+
+    /// Refreshes *only* the process corresponding to `pid`. Returns `false` if the process doesn't
+    /// exist. If it isn't listed yet, it'll be added.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_process(1337);
+    /// ```
+    fn refresh_process(&mut self, pid: Pid) -> bool;
+
+    /// Refreshes the listed disks' information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_disks();
+    /// ```
+    fn refresh_disks(&mut self) {
+        for disk in self.get_disks_mut() {
+            disk.refresh();
+        }
+    }
+
+    /// The disk list will be emptied then completely recomputed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_disks_list();
+    /// ```
+    fn refresh_disks_list(&mut self);
+
+    /// Refreshes users list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_users_list();
+    /// ```
+    fn refresh_users_list(&mut self);
+
+    /// Refreshes networks data.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_networks();
+    /// ```
+    ///
+    /// It is a shortcut for:
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworksExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let networks = s.get_networks_mut();
+    /// networks.refresh();
+    /// ```
+    fn refresh_networks(&mut self) {
+        self.get_networks_mut().refresh();
+    }
+
+    /// The network list will be updated: removing not existing anymore interfaces and adding new
+    /// ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_networks_list();
+    /// ```
+    ///
+    /// This is a shortcut for:
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworksExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let networks = s.get_networks_mut();
+    /// networks.refresh_networks_list();
+    /// ```
+    fn refresh_networks_list(&mut self) {
+        self.get_networks_mut().refresh_networks_list();
+    }
+
+    /// Refreshes block devices' I/O statistics.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_block_devices();
+    /// ```
+    ///
+    /// It is a shortcut for:
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDevicesExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let block_devices = s.get_block_devices_mut();
+    /// block_devices.refresh();
+    /// ```
+    fn refresh_block_devices(&mut self) {
+        self.get_block_devices_mut().refresh();
+    }
+
+    /// The block devices list will be updated: removing not existing anymore devices and adding
+    /// new ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_block_devices_list();
+    /// ```
+    ///
+    /// This is a shortcut for:
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDevicesExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let block_devices = s.get_block_devices_mut();
+    /// block_devices.refresh_block_devices_list();
+    /// ```
+    fn refresh_block_devices_list(&mut self) {
+        self.get_block_devices_mut().refresh_block_devices_list();
+    }
+
+    /// Refreshes all system, processes, disks, network interfaces and block devices information.
+    ///
+    /// Please note that it doesn't recompute disks list, components list, network interfaces
+    /// list, block devices list nor users list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_all();
+    /// ```
+    fn refresh_all(&mut self) {
+        self.refresh_system();
+        self.refresh_processes();
+        self.refresh_disks();
+        self.refresh_networks();
+        self.refresh_block_devices();
+    }
+
+    /// Returns the process list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for (pid, process) in s.get_processes() {
+    ///     println!("{} {}", pid, process.name());
+    /// }
+    /// ```
+    fn get_processes(&self) -> &HashMap<Pid, Process>;
+
+    /// Returns the process corresponding to the given pid or `None` if no such process exists.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.get_process(1337) {
+    ///     println!("{}", process.name());
+    /// }
+    /// ```
+    fn get_process(&self, pid: Pid) -> Option<&Process>;
+
+    /// Returns a list of process containing the given `name`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for process in s.get_process_by_name("htop") {
+    ///     println!("{} {}", process.pid(), process.name());
+    /// }
+    /// ```
+    fn get_process_by_name(&self, name: &str) -> Vec<&Process> {
+        let mut ret = vec![];
+        for val in self.get_processes().values() {
+            if val.name().contains(name) {
+                ret.push(val);
+            }
+        }
+        ret
+    }
+
+    /// Returns the processes matching the given `query`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessQuery, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let query = ProcessQuery::new().name("*sh").cmd_contains("--login");
+    /// for process in s.find_processes(&query) {
+    ///     println!("{} {}", process.pid(), process.name());
+    /// }
+    /// ```
+    fn find_processes(&self, query: &ProcessQuery) -> Vec<&Process> {
+        self.get_processes()
+            .values()
+            .filter(|process| query.matches(process))
+            .collect()
+    }
+
+    /// Returns the (at most) `n` processes matching the given `query` with the highest values
+    /// for the given `sort` criteria, sorted in decreasing order.
+    ///
+    /// Only the top `n` processes are sorted, not all the matching ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, ProcessQuery, ProcessSort, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for process in s.top_processes(&ProcessQuery::new(), ProcessSort::Memory, 5) {
+    ///     println!("{} {}: {} kB", process.pid(), process.name(), process.memory());
+    /// }
+    /// ```
+    fn top_processes(&self, query: &ProcessQuery, sort: ProcessSort, n: usize) -> Vec<&Process> {
+        if n == 0 {
+            return Vec::new();
+        }
+        // This is a min-heap containing the `n` highest values found so far.
+        let mut heap = BinaryHeap::with_capacity(n + 1);
+        for process in self.get_processes().values() {
+            if !query.matches(process) {
+                continue;
+            }
+            heap.push(Reverse(RankedProcess {
+                value: sort.get_value(process),
+                process,
+            }));
+            if heap.len() > n {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(ranked)| ranked.process)
+            .collect()
+    }
+
+    /// Returns the direct children of the process corresponding to the given pid.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for process in s.get_process_children(1) {
+    ///     println!("{} {}", process.pid(), process.name());
+    /// }
+    /// ```
+    fn get_process_children(&self, pid: Pid) -> Vec<&Process> {
+        self.get_processes()
+            .values()
+            .filter(|p| p.parent() == Some(pid) && p.pid() != pid)
+            .collect()
+    }
+
+    /// Returns all the descendants (children, children of children, etc) of the process
+    /// corresponding to the given pid. Processes closer to `pid` come first.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for process in s.get_process_descendants(1) {
+    ///     println!("{} {}", process.pid(), process.name());
+    /// }
+    /// ```
+    fn get_process_descendants(&self, pid: Pid) -> Vec<&Process> {
+        let mut children: HashMap<Pid, Vec<&Process>> = HashMap::new();
+        for process in self.get_processes().values() {
+            if let Some(parent) = process.parent() {
+                if parent != process.pid() {
+                    children.entry(parent).or_default().push(process);
+                }
+            }
+        }
+
+        let mut ret: Vec<&Process> = Vec::new();
+        let mut seen = HashSet::new();
+        seen.insert(pid);
+        let mut pos = 0;
+        let mut current = Some(pid);
+        while let Some(pid) = current {
+            if let Some(children) = children.get(&pid) {
+                // A pid can be reused, so we need to be careful about cycles.
+                ret.extend(children.iter().filter(|p| seen.insert(p.pid())));
+            }
+            current = ret.get(pos).map(|p| p.pid());
+            pos += 1;
+        }
+        ret
+    }
+
+    /// Returns the ancestors of the process corresponding to the given pid, starting with its
+    /// parent and going up to the root of the processes tree.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for process in s.get_process_ancestors(1337) {
+    ///     println!("{} {}", process.pid(), process.name());
+    /// }
+    /// ```
+    fn get_process_ancestors(&self, pid: Pid) -> Vec<&Process> {
+        let mut ret: Vec<&Process> = Vec::new();
+        let mut seen = HashSet::new();
+        seen.insert(pid);
+        let mut current = self.get_process(pid).and_then(|p| p.parent());
+        while let Some(parent) = current {
+            // A pid can be reused, so we need to be careful about cycles.
+            if !seen.insert(parent) {
+                break;
+            }
+            match self.get_process(parent) {
+                Some(process) => {
+                    ret.push(process);
+                    current = process.parent();
+                }
+                None => break,
+            }
+        }
+        ret
+    }
+
+    /// Returns the sum of the CPU usage of the process corresponding to the given pid and of all
+    /// its descendants. It returns `0.` if there is no such process.
+    ///
+    /// Just like [`ProcessExt::cpu_usage`], it needs at least two refreshes to be relevant.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{}%", s.get_process_tree_cpu_usage(1337));
+    /// ```
+    fn get_process_tree_cpu_usage(&self, pid: Pid) -> f32 {
+        match self.get_process(pid) {
+            Some(process) => self
+                .get_process_descendants(pid)
+                .iter()
+                .fold(process.cpu_usage(), |total, p| total + p.cpu_usage()),
+            None => 0.,
+        }
+    }
+
+    /// Returns the sum of the memory usage (in kB) of the process corresponding to the given pid
+    /// and of all its descendants. It returns `0` if there is no such process.
+    ///
+    /// Memory shared between processes is counted once per process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_process_tree_memory(1337));
+    /// ```
+    fn get_process_tree_memory(&self, pid: Pid) -> u64 {
+        match self.get_process(pid) {
+            Some(process) => self
+                .get_process_descendants(pid)
+                .iter()
+                .fold(process.memory(), |total, p| total + p.memory()),
+            None => 0,
+        }
+    }
+
+    /// Returns the namespaces of all the processes with the processes in each of them (like
+    /// `lsns`). The processes of a namespace are sorted by pid.
+    ///
+    /// Just like [`ProcessExt::namespaces`], the information is read when this method is called
+    /// so it can be slow with a lot of processes. Processes whose namespaces can't be read are
+    /// ignored.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NamespaceKind, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for (namespace, pids) in s.get_namespaces() {
+    ///     if namespace.kind == NamespaceKind::Net {
+    ///         println!("{}: {:?}", namespace.id, pids);
+    ///     }
+    /// }
+    /// ```
+    fn get_namespaces(&self) -> HashMap<Namespace, Vec<Pid>> {
+        let mut namespaces: HashMap<Namespace, Vec<Pid>> = HashMap::new();
+        for (pid, process) in self.get_processes() {
+            for namespace in process.namespaces().unwrap_or_default() {
+                namespaces.entry(namespace).or_default().push(*pid);
+            }
+        }
+        for pids in namespaces.values_mut() {
+            pids.sort_unstable();
+        }
+        namespaces
+    }
+
+    /// Returns the TCP, UDP and Unix sockets of the system (like `ss -p`), linked to the
+    /// processes which have them open.
+    ///
+    /// The information is read when this method is called. Don't forget to refresh the
+    /// processes list first since only the processes returned by
+    /// [`SystemExt::get_processes`] are checked. Only the sockets of the network namespace of
+    /// the current process are returned and it returns an empty list if the information isn't
+    /// available (only Linux provides it for the moment).
+    ///
+    /// ```no_run
+    /// use sysinfo::{SocketState, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// // Which process is listening on port 8080?
+    /// for socket in s.get_sockets() {
+    ///     if socket.state == SocketState::Listen
+    ///         && socket.local_address.map(|addr| addr.port()) == Some(8080)
+    ///     {
+    ///         for pid in &socket.pids {
+    ///             println!("{:?}", s.get_process(*pid));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn get_sockets(&self) -> Vec<Socket>;
+
+    /// Returns "global" processors information (aka the addition of all the processors).
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// println!("{}%", s.get_global_processor_info().get_cpu_usage());
+    /// ```
+    fn get_global_processor_info(&self) -> &Processor;
+
+    /// Returns the list of the processors.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// for processor in s.get_processors() {
+    ///     println!("{}%", processor.get_cpu_usage());
+    /// }
+    /// ```
+    fn get_processors(&self) -> &[Processor];
+
+    /// Returns the RAM size in kB.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_total_memory());
+    /// ```
+    fn get_total_memory(&self) -> u64;
+
+    /// Returns the amount of free RAM in kB.
+    ///
+    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
+    /// memory that is available for (re)use.
+    ///
+    /// Side note: Windows doesn't report "free" memory so this method returns the same value
+    /// as [`get_available_memory`](#tymethod.get_available_memory).
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_free_memory());
+    /// ```
+    fn get_free_memory(&self) -> u64;
+
+    /// Returns the amount of available RAM in kB.
+    ///
+    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
+    /// memory that is available for (re)use.
+    ///
+    /// Side note: Windows doesn't report "free" memory so
+    /// [`get_free_memory`](#tymethod.get_free_memory) returns the same value as this method.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_available_memory());
+    /// ```
+    fn get_available_memory(&self) -> u64;
+
+    /// Returns the amound of used RAM in kB.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_used_memory());
+    /// ```
+    fn get_used_memory(&self) -> u64;
+
+    /// Returns the SWAP size in kB.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_total_swap());
+    /// ```
+    fn get_total_swap(&self) -> u64;
+
+    /// Returns the amount of free SWAP in kB.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_free_swap());
+    /// ```
+    fn get_free_swap(&self) -> u64;
+
+    /// Returns the amount of used SWAP in kB.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("{} kB", s.get_used_swap());
+    /// ```
+    fn get_used_swap(&self) -> u64;
+
+    /// Returns the components list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ComponentExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for component in s.get_components() {
+    ///     println!("{}: {}°C", component.get_label(), component.get_temperature());
+    /// }
+    /// ```
+    fn get_components(&self) -> &[Component];
+
+    /// Returns a mutable components list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ComponentExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// for component in s.get_components_mut() {
+    ///     component.refresh();
+    /// }
+    /// ```
+    fn get_components_mut(&mut self) -> &mut [Component];
+
+    /// Returns the disks list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for disk in s.get_disks() {
+    ///     println!("{:?}", disk.get_name());
+    /// }
+    /// ```
+    fn get_disks(&self) -> &[Disk];
+
+    /// Returns a mutable disks list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// for disk in s.get_disks_mut() {
+    ///     disk.refresh();
+    /// }
+    /// ```
+    fn get_disks_mut(&mut self) -> &mut [Disk];
+
+    /// Returns the users list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt, UserExt};
+    ///
+    /// let mut s = System::new_all();
+    /// for user in s.get_users() {
+    ///     println!("{} is in {} groups", user.get_name(), user.get_groups().len());
+    /// }
+    /// ```
+    fn get_users(&self) -> &[User];
+
+    /// Returns the user with the given user id.
+    ///
+    /// Unlike [`SystemExt::get_users`], system accounts without a login shell (like the ones
+    /// running daemons) can be found as well. Don't forget to refresh the users list first. On
+    /// Windows, users don't have a numeric id so it always returns `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessExt, System, SystemExt, UserExt};
+    ///
+    /// let s = System::new_all();
+    /// for (pid, process) in s.get_processes() {
+    ///     if let Some(user) = process.user_id().and_then(|uid| s.get_user_by_id(uid)) {
+    ///         println!("{} is run by {}", pid, user.get_name());
+    ///     }
+    /// }
+    /// ```
+    fn get_user_by_id(&self, uid: Uid) -> Option<&User>;
+
+    /// Returns the network interfaces object.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, data) in networks {
+    ///     println!("[{}] in: {}, out: {}", interface_name, data.get_received(), data.get_transmitted());
+    /// }
+    /// ```
+    fn get_networks(&self) -> &Networks;
+
+    /// Returns a mutable access to network interfaces.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let networks = s.get_networks_mut();
+    /// networks.refresh_networks_list();
+    /// ```
+    fn get_networks_mut(&mut self) -> &mut Networks;
+
+    /// Returns block devices' I/O statistics.
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let block_devices = s.get_block_devices();
+    /// for (name, device) in block_devices {
+    ///     println!("[{}] await: {} ms", name, device.get_await());
+    /// }
+    /// ```
+    fn get_block_devices(&self) -> &BlockDevices;
+
+    /// Returns a mutable access to block devices.
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDevicesExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let block_devices = s.get_block_devices_mut();
+    /// block_devices.refresh_block_devices_list();
+    /// ```
+    fn get_block_devices_mut(&mut self) -> &mut BlockDevices;
+
+    /// Returns system uptime (in seconds).
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// println!("System running since {} seconds", s.get_uptime());
+    /// ```
+    fn get_uptime(&self) -> u64;
+
+    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new();
+    /// println!("System booted at {} seconds", s.get_boot_time());
+    /// ```
+    fn get_boot_time(&self) -> u64;
+
+    /// Returns the system load average value.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let load_avg = s.get_load_average();
+    /// println!(
+    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+    ///     load_avg.one,
+    ///     load_avg.five,
+    ///     load_avg.fifteen,
+    /// );
+    /// ```
+    fn get_load_average(&self) -> LoadAvg;
+}
+
+/// Getting volume of received and transmitted data.
+pub trait NetworkExt: Debug {
+    /// Returns the number of received bytes since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {} B", network.get_received());
+    /// }
+    /// ```
+    fn get_received(&self) -> u64;
+
+    /// Returns the total number of received bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {} B", network.get_total_received());
+    /// }
+    /// ```
+    fn get_total_received(&self) -> u64;
+
+    /// Returns the number of transmitted bytes since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("out: {} B", network.get_transmitted());
+    /// }
+    /// ```
+    fn get_transmitted(&self) -> u64;
+
+    /// Returns the total number of transmitted bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("out: {} B", network.get_total_transmitted());
+    /// }
+    /// ```
+    fn get_total_transmitted(&self) -> u64;
+
+    /// Returns the number of incoming packets since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {}", network.get_packets_received());
+    /// }
+    /// ```
+    fn get_packets_received(&self) -> u64;
+
+    /// Returns the total number of incoming packets.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {}", network.get_total_packets_received());
+    /// }
+    /// ```
+    fn get_total_packets_received(&self) -> u64;
+
+    /// Returns the number of outcoming packets since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("out: {}", network.get_packets_transmitted());
+    /// }
+    /// ```
+    fn get_packets_transmitted(&self) -> u64;
+
+    /// Returns the total number of outcoming packets.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("out: {}", network.get_total_packets_transmitted());
+    /// }
+    /// ```
+    fn get_total_packets_transmitted(&self) -> u64;
+
+    /// Returns the number of incoming errors since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {}", network.get_errors_on_received());
+    /// }
+    /// ```
+    fn get_errors_on_received(&self) -> u64;
+
+    /// Returns the total number of incoming errors.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {}", network.get_total_errors_on_received());
+    /// }
+    /// ```
+    fn get_total_errors_on_received(&self) -> u64;
+
+    /// Returns the number of outcoming errors since the last refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("out: {}", network.get_errors_on_transmitted());
+    /// }
+    /// ```
+    fn get_errors_on_transmitted(&self) -> u64;
+
+    /// Returns the total number of outcoming errors.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("out: {}", network.get_total_errors_on_transmitted());
+    /// }
+    /// ```
+    fn get_total_errors_on_transmitted(&self) -> u64;
+}
+
+/// Interacting with network interfaces.
+pub trait NetworksExt: Debug {
+    /// Returns an iterator over the network interfaces.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// let networks = s.get_networks();
+    /// for (interface_name, network) in networks {
+    ///     println!("in: {} B", network.get_received());
+    /// }
+    /// ```
+    fn iter(&self) -> NetworksIter;
+
+    /// Refreshes the network interfaces list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworksExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let networks = s.get_networks_mut();
+    /// networks.refresh_networks_list();
+    /// ```
+    fn refresh_networks_list(&mut self);
+
+    /// Refreshes the network interfaces' content.
+    ///
+    /// ```no_run
+    /// use sysinfo::{NetworksExt, System, SystemExt};
+    ///
+    /// let mut s = System::new_all();
+    /// let networks = s.get_networks_mut();
+    /// networks.refresh();
+    /// ```
+    fn refresh(&mut self);
+}
+
+/// Getting information and I/O statistics of a block device.
+pub trait BlockDeviceExt: Debug {
+    /// Returns the kind of the device (HDD or SSD).
+    ///
+    /// For a partition, the kind of the disk it belongs to is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {:?}", name, device.get_kind());
+    /// }
+    /// ```
+    fn get_kind(&self) -> DiskKind;
+
+    /// Returns the model of the device, or an empty string if it isn't available.
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {}", name, device.get_model());
+    /// }
+    /// ```
+    fn get_model(&self) -> &str;
+
+    /// Returns the serial number of the device, or an empty string if it isn't available.
+    ///
+    /// ```no_run
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
+    ///
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {}", name, device.get_serial());
     /// }
     /// ```
-    fn get_process(&self, pid: Pid) -> Option<&Process>;
+    fn get_serial(&self) -> &str;
 
-    /// Returns a list of process containing the given `name`.
+    /// Returns `true` if the device is removable (like an USB stick or a CD-ROM drive).
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// for process in s.get_process_by_name("htop") {
-    ///     println!("{} {}", process.pid(), process.name());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {}", name, device.is_removable());
     /// }
     /// ```
-    fn get_process_by_name(&self, name: &str) -> Vec<&Process> {
-        let mut ret = vec![];
-        for val in self.get_processes().values() {
-            if val.name().contains(name) {
-                ret.push(val);
-            }
-        }
-        ret
-    }
+    fn is_removable(&self) -> bool;
 
-    /// Returns "global" processors information (aka the addition of all the processors).
+    /// Returns the size of the device (in bytes).
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
-    /// let s = System::new();
-    /// println!("{}%", s.get_global_processor_info().get_cpu_usage());
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} B", name, device.get_size());
+    /// }
     /// ```
-    fn get_global_processor_info(&self) -> &Processor;
+    fn get_size(&self) -> u64;
 
-    /// Returns the list of the processors.
+    /// Returns the name of the disk this device is a partition of.
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessorExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
-    /// let s = System::new();
-    /// for processor in s.get_processors() {
-    ///     println!("{}%", processor.get_cpu_usage());
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {:?}", name, device.get_parent());
     /// }
     /// ```
-    fn get_processors(&self) -> &[Processor];
+    fn get_parent(&self) -> Option<&str>;
 
-    /// Returns the RAM size in kB.
+    /// Returns the names of the partitions of this device.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_total_memory());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {:?}", name, device.get_partitions());
+    /// }
     /// ```
-    fn get_total_memory(&self) -> u64;
+    fn get_partitions(&self) -> &[String];
 
-    /// Returns the amount of free RAM in kB.
-    ///
-    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
-    /// memory that is available for (re)use.
-    ///
-    /// Side note: Windows doesn't report "free" memory so this method returns the same value
-    /// as [`get_available_memory`](#tymethod.get_available_memory).
+    /// Returns the names of the devices this device is built upon (like the devices under a
+    /// device-mapper or a RAID array).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_free_memory());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {:?}", name, device.get_slaves());
+    /// }
     /// ```
-    fn get_free_memory(&self) -> u64;
+    fn get_slaves(&self) -> &[String];
 
-    /// Returns the amount of available RAM in kB.
-    ///
-    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
-    /// memory that is available for (re)use.
-    ///
-    /// Side note: Windows doesn't report "free" memory so
-    /// [`get_free_memory`](#tymethod.get_free_memory) returns the same value as this method.
+    /// Returns the names of the devices built upon this device (like a device-mapper or a RAID
+    /// array using it).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_available_memory());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {:?}", name, device.get_holders());
+    /// }
     /// ```
-    fn get_available_memory(&self) -> u64;
+    fn get_holders(&self) -> &[String];
 
-    /// Returns the amound of used RAM in kB.
+    /// Returns the device-mapper name of this device (like `vg0-root` for a LVM logical volume).
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_used_memory());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {:?}", name, device.get_dm_name());
+    /// }
     /// ```
-    fn get_used_memory(&self) -> u64;
+    fn get_dm_name(&self) -> Option<&str>;
 
-    /// Returns the SWAP size in kB.
+    /// Returns the status of the software RAID array if this device is one.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_total_swap());
+    /// for (name, device) in s.get_block_devices() {
+    ///     if let Some(status) = device.get_raid_status() {
+    ///         println!("{}: degraded: {}", name, status.is_degraded());
+    ///     }
+    /// }
     /// ```
-    fn get_total_swap(&self) -> u64;
+    fn get_raid_status(&self) -> Option<&RaidStatus>;
 
-    /// Returns the amount of free SWAP in kB.
+    /// Returns the number of reads completed since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_free_swap());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} reads", name, device.get_reads_completed());
+    /// }
     /// ```
-    fn get_free_swap(&self) -> u64;
+    fn get_reads_completed(&self) -> u64;
 
-    /// Returns the amount of used SWAP in kB.
+    /// Returns the total number of reads completed.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("{} kB", s.get_used_swap());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} reads", name, device.get_total_reads_completed());
+    /// }
     /// ```
-    fn get_used_swap(&self) -> u64;
+    fn get_total_reads_completed(&self) -> u64;
 
-    /// Returns the components list.
+    /// Returns the number of sectors read since the last refresh.
+    ///
+    /// A sector is always 512 bytes long here, whatever the actual sector size of the device.
     ///
     /// ```no_run
-    /// use sysinfo::{ComponentExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// for component in s.get_components() {
-    ///     println!("{}: {}°C", component.get_label(), component.get_temperature());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} B read", name, device.get_sectors_read() * 512);
     /// }
     /// ```
-    fn get_components(&self) -> &[Component];
+    fn get_sectors_read(&self) -> u64;
 
-    /// Returns a mutable components list.
+    /// Returns the total number of sectors read.
     ///
     /// ```no_run
-    /// use sysinfo::{ComponentExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// for component in s.get_components_mut() {
-    ///     component.refresh();
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} B read", name, device.get_total_sectors_read() * 512);
     /// }
     /// ```
-    fn get_components_mut(&mut self) -> &mut [Component];
+    fn get_total_sectors_read(&self) -> u64;
 
-    /// Returns the users list.
+    /// Returns the time spent reading (in milliseconds) since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt, UserExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// for user in s.get_users() {
-    ///     println!("{} is in {} groups", user.get_name(), user.get_groups().len());
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_time_reading());
     /// }
     /// ```
-    fn get_users(&self) -> &[User];
+    fn get_time_reading(&self) -> u64;
 
-    /// Returns the network interfaces object.
+    /// Returns the total time spent reading (in milliseconds).
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, data) in networks {
-    ///     println!("[{}] in: {}, out: {}", interface_name, data.get_received(), data.get_transmitted());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_total_time_reading());
     /// }
     /// ```
-    fn get_networks(&self) -> &Networks;
+    fn get_total_time_reading(&self) -> u64;
 
-    /// Returns a mutable access to network interfaces.
+    /// Returns the number of writes completed since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
-    /// let mut s = System::new_all();
-    /// let networks = s.get_networks_mut();
-    /// networks.refresh_networks_list();
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} writes", name, device.get_writes_completed());
+    /// }
     /// ```
-    fn get_networks_mut(&mut self) -> &mut Networks;
+    fn get_writes_completed(&self) -> u64;
 
-    /// Returns system uptime (in seconds).
+    /// Returns the total number of writes completed.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// println!("System running since {} seconds", s.get_uptime());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} writes", name, device.get_total_writes_completed());
+    /// }
     /// ```
-    fn get_uptime(&self) -> u64;
+    fn get_total_writes_completed(&self) -> u64;
 
-    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    /// Returns the number of sectors written since the last refresh.
+    ///
+    /// A sector is always 512 bytes long here, whatever the actual sector size of the device.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
-    /// let s = System::new();
-    /// println!("System booted at {} seconds", s.get_boot_time());
+    /// let s = System::new_all();
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} B written", name, device.get_sectors_written() * 512);
+    /// }
     /// ```
-    fn get_boot_time(&self) -> u64;
+    fn get_sectors_written(&self) -> u64;
 
-    /// Returns the system load average value.
+    /// Returns the total number of sectors written.
     ///
     /// ```no_run
-    /// use sysinfo::{System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let load_avg = s.get_load_average();
-    /// println!(
-    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-    ///     load_avg.one,
-    ///     load_avg.five,
-    ///     load_avg.fifteen,
-    /// );
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} B written", name, device.get_total_sectors_written() * 512);
+    /// }
     /// ```
-    fn get_load_average(&self) -> LoadAvg;
-}
+    fn get_total_sectors_written(&self) -> u64;
 
-/// Getting volume of received and transmitted data.
-pub trait NetworkExt: Debug {
-    /// Returns the number of received bytes since the last refresh.
+    /// Returns the time spent writing (in milliseconds) since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {} B", network.get_received());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_time_writing());
     /// }
     /// ```
-    fn get_received(&self) -> u64;
+    fn get_time_writing(&self) -> u64;
 
-    /// Returns the total number of received bytes.
+    /// Returns the total time spent writing (in milliseconds).
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {} B", network.get_total_received());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_total_time_writing());
     /// }
     /// ```
-    fn get_total_received(&self) -> u64;
+    fn get_total_time_writing(&self) -> u64;
 
-    /// Returns the number of transmitted bytes since the last refresh.
+    /// Returns the number of I/Os currently in progress.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("out: {} B", network.get_transmitted());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} in flight", name, device.get_ios_in_progress());
     /// }
     /// ```
-    fn get_transmitted(&self) -> u64;
+    fn get_ios_in_progress(&self) -> u64;
 
-    /// Returns the total number of transmitted bytes.
+    /// Returns the time spent doing I/Os (in milliseconds) since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("out: {} B", network.get_total_transmitted());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_time_doing_io());
     /// }
     /// ```
-    fn get_total_transmitted(&self) -> u64;
+    fn get_time_doing_io(&self) -> u64;
 
-    /// Returns the number of incoming packets since the last refresh.
+    /// Returns the total time spent doing I/Os (in milliseconds).
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {}", network.get_packets_received());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_total_time_doing_io());
     /// }
     /// ```
-    fn get_packets_received(&self) -> u64;
+    fn get_total_time_doing_io(&self) -> u64;
 
-    /// Returns the total number of incoming packets.
+    /// Returns the weighted time spent doing I/Os (in milliseconds) since the last refresh.
+    ///
+    /// Each I/O in progress adds its time to this value, so it grows faster than
+    /// [`get_time_doing_io`][BlockDeviceExt::get_time_doing_io] when I/Os are queued.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {}", network.get_total_packets_received());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_weighted_time_doing_io());
     /// }
     /// ```
-    fn get_total_packets_received(&self) -> u64;
+    fn get_weighted_time_doing_io(&self) -> u64;
 
-    /// Returns the number of outcoming packets since the last refresh.
+    /// Returns the total weighted time spent doing I/Os (in milliseconds).
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("out: {}", network.get_packets_transmitted());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_total_weighted_time_doing_io());
     /// }
     /// ```
-    fn get_packets_transmitted(&self) -> u64;
+    fn get_total_weighted_time_doing_io(&self) -> u64;
 
-    /// Returns the total number of outcoming packets.
+    /// Returns the time elapsed (in milliseconds) between the two last refreshes.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("out: {}", network.get_total_packets_transmitted());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_elapsed_time());
     /// }
     /// ```
-    fn get_total_packets_transmitted(&self) -> u64;
+    fn get_elapsed_time(&self) -> u64;
 
-    /// Returns the number of incoming errors since the last refresh.
+    /// Returns the average time (in milliseconds) for I/O requests completed since the last
+    /// refresh to be served, including the time spent in queue.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {}", network.get_errors_on_received());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {} ms", name, device.get_await());
     /// }
     /// ```
-    fn get_errors_on_received(&self) -> u64;
+    fn get_await(&self) -> f32 {
+        let ios = self.get_reads_completed() + self.get_writes_completed();
+        if ios == 0 {
+            return 0.;
+        }
+        (self.get_time_reading() + self.get_time_writing()) as f32 / ios as f32
+    }
 
-    /// Returns the total number of incoming errors.
+    /// Returns the percentage of time the device was busy doing I/Os since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {}", network.get_total_errors_on_received());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {}%", name, device.get_utilization());
     /// }
     /// ```
-    fn get_total_errors_on_received(&self) -> u64;
+    fn get_utilization(&self) -> f32 {
+        let elapsed = self.get_elapsed_time();
+        if elapsed == 0 {
+            return 0.;
+        }
+        (self.get_time_doing_io() as f32 * 100. / elapsed as f32).min(100.)
+    }
 
-    /// Returns the number of outcoming errors since the last refresh.
+    /// Returns the average number of I/O requests queued on the device since the last refresh.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("out: {}", network.get_errors_on_transmitted());
+    /// for (name, device) in s.get_block_devices() {
+    ///     println!("{}: {}", name, device.get_average_queue_size());
     /// }
     /// ```
-    fn get_errors_on_transmitted(&self) -> u64;
+    fn get_average_queue_size(&self) -> f32 {
+        let elapsed = self.get_elapsed_time();
+        if elapsed == 0 {
+            return 0.;
+        }
+        self.get_weighted_time_doing_io() as f32 / elapsed as f32
+    }
+}
 
-    /// Returns the total number of outcoming errors.
+/// Interacting with block devices.
+pub trait BlockDevicesExt: Debug {
+    /// Returns an iterator over the block devices.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("out: {}", network.get_total_errors_on_transmitted());
+    /// let block_devices = s.get_block_devices();
+    /// for (name, device) in block_devices.iter() {
+    ///     println!("{}: {}%", name, device.get_utilization());
     /// }
     /// ```
-    fn get_total_errors_on_transmitted(&self) -> u64;
-}
+    fn iter(&self) -> BlockDevicesIter<'_>;
 
-/// Interacting with network interfaces.
-pub trait NetworksExt: Debug {
-    /// Returns an iterator over the network interfaces.
+    /// Returns the block device with the given name.
+    ///
+    /// It can be used to walk through the devices topology:
     ///
     /// ```no_run
-    /// use sysinfo::{NetworkExt, NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDeviceExt, BlockDevicesExt, System, SystemExt};
     ///
     /// let s = System::new_all();
-    /// let networks = s.get_networks();
-    /// for (interface_name, network) in networks {
-    ///     println!("in: {} B", network.get_received());
+    /// let block_devices = s.get_block_devices();
+    /// if let Some(disk) = block_devices.get("sda") {
+    ///     for partition_name in disk.get_partitions() {
+    ///         if let Some(partition) = block_devices.get(partition_name) {
+    ///             println!("{} is used by {:?}", partition_name, partition.get_holders());
+    ///         }
+    ///     }
     /// }
     /// ```
-    fn iter(&self) -> NetworksIter;
+    fn get(&self, name: &str) -> Option<&BlockDevice>;
 
-    /// Refreshes the network interfaces list.
+    /// Refreshes the block devices list.
     ///
     /// ```no_run
-    /// use sysinfo::{NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDevicesExt, System, SystemExt};
     ///
     /// let mut s = System::new_all();
-    /// let networks = s.get_networks_mut();
-    /// networks.refresh_networks_list();
+    /// let block_devices = s.get_block_devices_mut();
+    /// block_devices.refresh_block_devices_list();
     /// ```
-    fn refresh_networks_list(&mut self);
+    fn refresh_block_devices_list(&mut self);
 
-    /// Refreshes the network interfaces' content.
+    /// Refreshes the block devices' content.
+    ///
+    /// The I/O statistics, the RAID status and the topology (partitions, slaves, holders, etc)
+    /// of the known devices are updated. New devices are only added by
+    /// [`BlockDevicesExt::refresh_block_devices_list`].
     ///
     /// ```no_run
-    /// use sysinfo::{NetworksExt, System, SystemExt};
+    /// use sysinfo::{BlockDevicesExt, System, SystemExt};
     ///
     /// let mut s = System::new_all();
-    /// let networks = s.get_networks_mut();
-    /// networks.refresh();
+    /// let block_devices = s.get_block_devices_mut();
+    /// block_devices.refresh();
     /// ```
     fn refresh(&mut self);
 }
@@ -1050,4 +2895,32 @@ pub trait UserExt: Debug {
     /// }
     /// ```
     fn get_groups(&self) -> &[String];
+
+    /// Returns the user id of the user.
+    ///
+    /// On Windows, it always returns `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt, UserExt};
+    ///
+    /// let mut s = System::new_all();
+    /// for user in s.get_users() {
+    ///     println!("{} has uid {}", user.get_name(), user.get_uid());
+    /// }
+    /// ```
+    fn get_uid(&self) -> Uid;
+
+    /// Returns the id of the primary group of the user.
+    ///
+    /// On Windows, it always returns `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, SystemExt, UserExt};
+    ///
+    /// let mut s = System::new_all();
+    /// for user in s.get_users() {
+    ///     println!("{} has gid {}", user.get_name(), user.get_gid());
+    /// }
+    /// ```
+    fn get_gid(&self) -> Gid;
 }
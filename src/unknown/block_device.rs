@@ -0,0 +1,168 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::collections::HashMap;
+
+use BlockDeviceExt;
+use BlockDevicesExt;
+use BlockDevicesIter;
+use DiskKind;
+use RaidStatus;
+
+/// Block devices.
+///
+/// ```no_run
+/// use sysinfo::{BlockDevicesExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// let block_devices = s.get_block_devices();
+/// ```
+pub struct BlockDevices {
+    devices: HashMap<String, BlockDevice>,
+}
+
+impl BlockDevices {
+    pub(crate) fn new() -> BlockDevices {
+        BlockDevices {
+            devices: HashMap::new(),
+        }
+    }
+}
+
+impl BlockDevicesExt for BlockDevices {
+    fn iter(&self) -> BlockDevicesIter<'_> {
+        BlockDevicesIter::new(self.devices.iter())
+    }
+
+    fn get(&self, name: &str) -> Option<&BlockDevice> {
+        self.devices.get(name)
+    }
+
+    fn refresh_block_devices_list(&mut self) {}
+
+    fn refresh(&mut self) {}
+}
+
+/// Contains block device information and I/O statistics.
+pub struct BlockDevice;
+
+impl BlockDeviceExt for BlockDevice {
+    fn get_kind(&self) -> DiskKind {
+        DiskKind::Unknown(-1)
+    }
+
+    fn get_model(&self) -> &str {
+        ""
+    }
+
+    fn get_serial(&self) -> &str {
+        ""
+    }
+
+    fn is_removable(&self) -> bool {
+        false
+    }
+
+    fn get_size(&self) -> u64 {
+        0
+    }
+
+    fn get_parent(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_partitions(&self) -> &[String] {
+        &[]
+    }
+
+    fn get_slaves(&self) -> &[String] {
+        &[]
+    }
+
+    fn get_holders(&self) -> &[String] {
+        &[]
+    }
+
+    fn get_dm_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_raid_status(&self) -> Option<&RaidStatus> {
+        None
+    }
+
+    fn get_reads_completed(&self) -> u64 {
+        0
+    }
+
+    fn get_total_reads_completed(&self) -> u64 {
+        0
+    }
+
+    fn get_sectors_read(&self) -> u64 {
+        0
+    }
+
+    fn get_total_sectors_read(&self) -> u64 {
+        0
+    }
+
+    fn get_time_reading(&self) -> u64 {
+        0
+    }
+
+    fn get_total_time_reading(&self) -> u64 {
+        0
+    }
+
+    fn get_writes_completed(&self) -> u64 {
+        0
+    }
+
+    fn get_total_writes_completed(&self) -> u64 {
+        0
+    }
+
+    fn get_sectors_written(&self) -> u64 {
+        0
+    }
+
+    fn get_total_sectors_written(&self) -> u64 {
+        0
+    }
+
+    fn get_time_writing(&self) -> u64 {
+        0
+    }
+
+    fn get_total_time_writing(&self) -> u64 {
+        0
+    }
+
+    fn get_ios_in_progress(&self) -> u64 {
+        0
+    }
+
+    fn get_time_doing_io(&self) -> u64 {
+        0
+    }
+
+    fn get_total_time_doing_io(&self) -> u64 {
+        0
+    }
+
+    fn get_weighted_time_doing_io(&self) -> u64 {
+        0
+    }
+
+    fn get_total_weighted_time_doing_io(&self) -> u64 {
+        0
+    }
+
+    fn get_elapsed_time(&self) -> u64 {
+        0
+    }
+}
@@ -0,0 +1,60 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2017 Guillaume Gomez
+//
+
+use DiskExt;
+use DiskKind;
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+/// Struct containing a disk information.
+pub struct Disk {}
+
+impl DiskExt for Disk {
+    fn get_kind(&self) -> DiskKind {
+        DiskKind::Unknown(-1)
+    }
+
+    fn get_name(&self) -> &OsStr {
+        OsStr::new("")
+    }
+
+    fn get_file_system(&self) -> &[u8] {
+        &[]
+    }
+
+    fn get_mount_point(&self) -> &Path {
+        Path::new("")
+    }
+
+    fn get_total_space(&self) -> u64 {
+        0
+    }
+
+    fn get_available_space(&self) -> u64 {
+        0
+    }
+
+    fn get_total_inodes(&self) -> u64 {
+        0
+    }
+
+    fn get_free_inodes(&self) -> u64 {
+        0
+    }
+
+    fn get_mount_options(&self) -> &[String] {
+        &[]
+    }
+
+    fn get_device_id(&self) -> (u32, u32) {
+        (0, 0)
+    }
+
+    fn refresh(&mut self) -> bool {
+        true
+    }
+}
@@ -4,14 +4,22 @@
 // Copyright (c) 2015 Guillaume Gomez
 //
 
+pub mod block_device;
 pub mod component;
+pub mod disk;
 pub mod network;
 pub mod process;
+pub mod process_handle;
 pub mod processor;
 pub mod system;
+pub mod thread;
 
+pub use self::block_device::{BlockDevice, BlockDevices};
 pub use self::component::Component;
+pub use self::disk::Disk;
 pub use self::network::{NetworkData, Networks};
 pub use self::process::{Process, ProcessStatus};
+pub use self::process_handle::ProcessHandle;
 pub use self::processor::Processor;
 pub use self::system::System;
+pub use self::thread::Thread;
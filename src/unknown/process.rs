@@ -4,12 +4,35 @@
 // Copyright (c) 2015 Guillaume Gomez
 //
 
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::io;
 use std::path::Path;
+use Cgroup;
+use CpuTime;
+use DiskUsage;
+use Gid;
+use KillError;
+use Limit;
+use MemoryBreakdown;
+use MemoryDetails;
+use MemoryMap;
+use Namespace;
+use OpenFile;
 use Pid;
+use ProcessActivity;
 use ProcessExt;
+use ProcessId;
+use Resource;
+use ResourceLimit;
+use SchedulingPolicy;
+use Uid;
+
+use sys::process_handle::ProcessHandle;
+use sys::thread::Thread;
 
 /// Enum describing the different status of a process.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ProcessStatus;
 
 /// Struct containing a process' information.
@@ -17,15 +40,24 @@ pub struct ProcessStatus;
 pub struct Process {
     pid: Pid,
     parent: Option<Pid>,
+    threads: HashMap<Pid, Thread>,
 }
 
 impl ProcessExt for Process {
     fn new(pid: Pid, parent: Option<Pid>, _start_time: u64) -> Process {
-        Process { pid, parent }
+        Process {
+            pid,
+            parent,
+            threads: HashMap::new(),
+        }
+    }
+
+    fn try_kill(&self, _signal: ::Signal) -> Result<(), KillError> {
+        Err(KillError::Unsupported)
     }
 
-    fn kill(&self, _signal: ::Signal) -> bool {
-        false
+    fn handle(&self) -> io::Result<ProcessHandle> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
     }
 
     fn name(&self) -> &str {
@@ -48,6 +80,14 @@ impl ProcessExt for Process {
         &[]
     }
 
+    fn cmd_os(&self) -> Option<&[OsString]> {
+        None
+    }
+
+    fn environ_os(&self) -> Option<&[OsString]> {
+        None
+    }
+
     fn cwd(&self) -> &Path {
         &Path::new("")
     }
@@ -76,7 +116,124 @@ impl ProcessExt for Process {
         0
     }
 
+    fn process_id(&self) -> ProcessId {
+        ProcessId {
+            pid: self.pid,
+            start_time: self.start_time(),
+            boot_id: 0,
+        }
+    }
+
     fn cpu_usage(&self) -> f32 {
         0.0
     }
+
+    fn disk_usage(&self) -> DiskUsage {
+        DiskUsage::default()
+    }
+
+    fn cpu_time(&self) -> Option<CpuTime> {
+        None
+    }
+
+    fn memory_breakdown(&self) -> Option<MemoryBreakdown> {
+        None
+    }
+
+    fn thread_count(&self) -> Option<u64> {
+        None
+    }
+
+    fn activity(&self) -> Option<ProcessActivity> {
+        None
+    }
+
+    fn nice(&self) -> Option<i32> {
+        None
+    }
+
+    fn priority(&self) -> Option<i64> {
+        None
+    }
+
+    fn scheduling_policy(&self) -> Option<SchedulingPolicy> {
+        None
+    }
+
+    fn realtime_priority(&self) -> Option<u32> {
+        None
+    }
+
+    fn set_nice(&self, _nice: i32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn set_scheduling_policy(&self, _policy: SchedulingPolicy, _priority: u32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn cpu_affinity(&self) -> Option<Vec<usize>> {
+        None
+    }
+
+    fn set_cpu_affinity(&self, _processors: &[usize]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn user_id(&self) -> Option<Uid> {
+        None
+    }
+
+    fn group_id(&self) -> Option<Gid> {
+        None
+    }
+
+    fn real_user_id(&self) -> Option<Uid> {
+        None
+    }
+
+    fn real_group_id(&self) -> Option<Gid> {
+        None
+    }
+
+    fn threads(&self) -> &HashMap<Pid, Thread> {
+        &self.threads
+    }
+
+    fn open_files(&self) -> Option<Vec<OpenFile>> {
+        None
+    }
+
+    fn open_files_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn memory_details(&self) -> Option<MemoryDetails> {
+        None
+    }
+
+    fn memory_maps(&self) -> Option<Vec<MemoryMap>> {
+        None
+    }
+
+    fn resource_limits(&self) -> Option<Vec<ResourceLimit>> {
+        None
+    }
+
+    fn set_resource_limit(
+        &self,
+        _resource: Resource,
+        _soft: Option<Limit>,
+        _hard: Option<Limit>,
+    ) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn cgroups(&self) -> Option<Vec<Cgroup>> {
+        None
+    }
+
+    fn namespaces(&self) -> Option<Vec<Namespace>> {
+        None
+    }
 }
@@ -7,9 +7,13 @@
 use sys::component::Component;
 use sys::process::*;
 use sys::processor::*;
+use sys::BlockDevices;
+use sys::Disk;
 use sys::Networks;
 use LoadAvg;
 use Pid;
+use Socket;
+use Uid;
 use User;
 use {RefreshKind, SystemExt};
 
@@ -19,6 +23,7 @@ use std::collections::HashMap;
 pub struct System {
     processes_list: HashMap<Pid, Process>,
     networks: Networks,
+    block_devices: BlockDevices,
     global_processor: Processor,
 }
 
@@ -27,6 +32,7 @@ impl SystemExt for System {
         System {
             processes_list: Default::default(),
             networks: Networks::new(),
+            block_devices: BlockDevices::new(),
             global_processor: Processor::new(),
         }
     }
@@ -43,6 +49,8 @@ impl SystemExt for System {
         false
     }
 
+    fn refresh_disks_list(&mut self) {}
+
     fn refresh_users_list(&mut self) {}
 
     // COMMON PART
@@ -65,6 +73,14 @@ impl SystemExt for System {
         &mut self.networks
     }
 
+    fn get_block_devices(&self) -> &BlockDevices {
+        &self.block_devices
+    }
+
+    fn get_block_devices_mut(&mut self) -> &mut BlockDevices {
+        &mut self.block_devices
+    }
+
     fn get_global_processor_info(&self) -> &Processor {
         &self.global_processor
     }
@@ -109,6 +125,14 @@ impl SystemExt for System {
         &mut []
     }
 
+    fn get_disks(&self) -> &[Disk] {
+        &[]
+    }
+
+    fn get_disks_mut(&mut self) -> &mut [Disk] {
+        &mut []
+    }
+
     fn get_uptime(&self) -> u64 {
         0
     }
@@ -128,6 +152,14 @@ impl SystemExt for System {
     fn get_users(&self) -> &[User] {
         &[]
     }
+
+    fn get_user_by_id(&self, _uid: Uid) -> Option<&User> {
+        None
+    }
+
+    fn get_sockets(&self) -> Vec<Socket> {
+        Vec::new()
+    }
 }
 
 impl Default for System {
@@ -7,9 +7,13 @@
 #[cfg(not(any(target_os = "windows", target_os = "unknown", target_arch = "wasm32")))]
 use std::ffi::OsStr;
 #[cfg(not(any(target_os = "windows", target_os = "unknown", target_arch = "wasm32")))]
+use std::io;
+#[cfg(not(any(target_os = "windows", target_os = "unknown", target_arch = "wasm32")))]
 use std::os::unix::ffi::OsStrExt;
 #[cfg(not(any(target_os = "windows", target_os = "unknown", target_arch = "wasm32")))]
 use std::path::Path;
+#[cfg(not(any(target_os = "windows", target_os = "unknown", target_arch = "wasm32")))]
+use KillError;
 use Pid;
 
 #[allow(clippy::useless_conversion)]
@@ -60,6 +64,19 @@ pub fn to_cpath(path: &Path) -> Vec<u8> {
     cpath
 }
 
+/// Converts an error returned when sending a signal into a `KillError`.
+#[cfg(not(any(target_os = "windows", target_os = "unknown", target_arch = "wasm32")))]
+pub(crate) fn get_kill_error(err: io::Error) -> KillError {
+    use libc::{EINVAL, EPERM, ESRCH};
+
+    match err.raw_os_error() {
+        Some(ESRCH) => KillError::NoSuchProcess,
+        Some(EPERM) => KillError::PermissionDenied,
+        Some(EINVAL) => KillError::InvalidSignal,
+        code => KillError::Other(code.unwrap_or(0)),
+    }
+}
+
 /// Returns the pid for the current process.
 ///
 /// `Err` is returned in case the platform isn't supported.
@@ -0,0 +1,114 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2018 Guillaume Gomez
+//
+
+use DiskExt;
+use DiskKind;
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use winapi::um::fileapi::GetDiskFreeSpaceExW;
+use winapi::um::winnt::ULARGE_INTEGER;
+
+pub fn new_disk(
+    name: &OsStr,
+    mount_point: &[u16],
+    file_system: &[u8],
+    kind: DiskKind,
+    mount_options: Vec<String>,
+) -> Option<Disk> {
+    let mut d = Disk {
+        kind,
+        name: name.to_owned(),
+        file_system: file_system.to_vec(),
+        mount_point: mount_point.to_vec(),
+        s_mount_point: PathBuf::from(String::from_utf16_lossy(
+            &mount_point[..mount_point.len() - 1],
+        )),
+        total_space: 0,
+        available_space: 0,
+        mount_options,
+    };
+    if d.refresh() && d.total_space != 0 {
+        Some(d)
+    } else {
+        None
+    }
+}
+
+/// Struct containing a disk information.
+pub struct Disk {
+    kind: DiskKind,
+    name: OsString,
+    file_system: Vec<u8>,
+    mount_point: Vec<u16>,
+    s_mount_point: PathBuf,
+    total_space: u64,
+    available_space: u64,
+    mount_options: Vec<String>,
+}
+
+impl DiskExt for Disk {
+    fn get_kind(&self) -> DiskKind {
+        self.kind
+    }
+
+    fn get_name(&self) -> &OsStr {
+        &self.name
+    }
+
+    fn get_file_system(&self) -> &[u8] {
+        &self.file_system
+    }
+
+    fn get_mount_point(&self) -> &Path {
+        &self.s_mount_point
+    }
+
+    fn get_total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    fn get_available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    fn get_total_inodes(&self) -> u64 {
+        0
+    }
+
+    fn get_free_inodes(&self) -> u64 {
+        0
+    }
+
+    fn get_mount_options(&self) -> &[String] {
+        &self.mount_options
+    }
+
+    fn get_device_id(&self) -> (u32, u32) {
+        (0, 0)
+    }
+
+    fn refresh(&mut self) -> bool {
+        unsafe {
+            let mut available: ULARGE_INTEGER = std::mem::zeroed();
+            let mut total: ULARGE_INTEGER = std::mem::zeroed();
+            if GetDiskFreeSpaceExW(
+                self.mount_point.as_ptr(),
+                &mut available,
+                &mut total,
+                std::ptr::null_mut(),
+            ) != 0
+            {
+                self.available_space = *available.QuadPart();
+                self.total_space = *total.QuadPart();
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
@@ -4,20 +4,28 @@
 // Copyright (c) 2015 Guillaume Gomez
 //
 
+mod block_device;
 mod component;
+mod disk;
 #[macro_use]
 mod macros;
 mod network;
 mod process;
+mod process_handle;
 mod processor;
 mod system;
+mod thread;
 mod tools;
 mod users;
 
 mod ffi;
 
+pub use self::block_device::{BlockDevice, BlockDevices};
 pub use self::component::Component;
+pub use self::disk::Disk;
 pub use self::network::{NetworkData, Networks};
 pub use self::process::{Process, ProcessStatus};
+pub use self::process_handle::ProcessHandle;
 pub use self::processor::Processor;
 pub use self::system::System;
+pub use self::thread::Thread;
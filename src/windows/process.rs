@@ -4,19 +4,43 @@
 // Copyright (c) 2018 Guillaume Gomez
 //
 
+use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fmt::{self, Debug};
+use std::io;
 use std::mem::{size_of, zeroed, MaybeUninit};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process;
 use std::ptr::null_mut;
 use std::str;
+use std::time::Duration;
 
 use libc::{c_void, memcpy};
 
 use once_cell::sync::Lazy;
+use Cgroup;
+use CpuTime;
+use DiskUsage;
+use Gid;
+use KillError;
+use Limit;
+use MemoryBreakdown;
+use MemoryDetails;
+use MemoryMap;
+use Namespace;
+use OpenFile;
 use Pid;
+use ProcessActivity;
 use ProcessExt;
+use ProcessId;
+use Resource;
+use ResourceLimit;
+use SchedulingPolicy;
+use Uid;
+
+use sys::process_handle::ProcessHandle;
+use sys::thread::Thread;
 
 use ntapi::ntpsapi::{
     NtQueryInformationProcess, ProcessBasicInformation, ProcessCommandLineInformation,
@@ -42,7 +66,7 @@ use winapi::um::winnt::{
 };
 
 /// Enum describing the different status of a process.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ProcessStatus {
     /// Currently runnable.
     Run,
@@ -107,6 +131,8 @@ pub struct Process {
     old_cpu: u64,
     old_sys_cpu: u64,
     old_user_cpu: u64,
+    sys_cpu_delta: u64,
+    user_cpu_delta: u64,
     start_time: u64,
     cpu_usage: f32,
     pub(crate) updated: bool,
@@ -114,6 +140,7 @@ pub struct Process {
     old_written_bytes: u64,
     read_bytes: u64,
     written_bytes: u64,
+    threads: HashMap<Pid, Thread>,
 }
 
 static WINDOWS_8_1_OR_NEWER: Lazy<bool> = Lazy::new(|| {
@@ -248,12 +275,15 @@ impl Process {
                 old_cpu: 0,
                 old_sys_cpu: 0,
                 old_user_cpu: 0,
+                sys_cpu_delta: 0,
+                user_cpu_delta: 0,
                 start_time: unsafe { get_start_time(handle) },
                 updated: true,
                 old_read_bytes: 0,
                 old_written_bytes: 0,
                 read_bytes: 0,
                 written_bytes: 0,
+                threads: HashMap::new(),
             }
         } else {
             Process {
@@ -273,12 +303,15 @@ impl Process {
                 old_cpu: 0,
                 old_sys_cpu: 0,
                 old_user_cpu: 0,
+                sys_cpu_delta: 0,
+                user_cpu_delta: 0,
                 start_time: 0,
                 updated: true,
                 old_read_bytes: 0,
                 old_written_bytes: 0,
                 read_bytes: 0,
                 written_bytes: 0,
+                threads: HashMap::new(),
             }
         }
     }
@@ -310,12 +343,15 @@ impl Process {
                 old_cpu: 0,
                 old_sys_cpu: 0,
                 old_user_cpu: 0,
+                sys_cpu_delta: 0,
+                user_cpu_delta: 0,
                 start_time: get_start_time(process_handler),
                 updated: true,
                 old_read_bytes: 0,
                 old_written_bytes: 0,
                 read_bytes: 0,
                 written_bytes: 0,
+                threads: HashMap::new(),
             }
         }
     }
@@ -348,25 +384,37 @@ impl ProcessExt for Process {
                 old_cpu: 0,
                 old_sys_cpu: 0,
                 old_user_cpu: 0,
+                sys_cpu_delta: 0,
+                user_cpu_delta: 0,
                 start_time: 0,
                 updated: true,
                 old_read_bytes: 0,
                 old_written_bytes: 0,
                 read_bytes: 0,
                 written_bytes: 0,
+                threads: HashMap::new(),
             }
         }
     }
 
-    fn kill(&self, _signal: ::Signal) -> bool {
+    fn try_kill(&self, _signal: ::Signal) -> Result<(), KillError> {
         let mut kill = process::Command::new("taskkill.exe");
         kill.arg("/PID").arg(self.pid().to_string()).arg("/F");
         match kill.output() {
-            Ok(o) => o.status.success(),
-            Err(_) => false,
+            Ok(ref o) if o.status.success() => Ok(()),
+            // `taskkill` returns 128 when the process wasn't found.
+            Ok(o) => match o.status.code() {
+                Some(128) => Err(KillError::NoSuchProcess),
+                code => Err(KillError::Other(code.unwrap_or(0))),
+            },
+            Err(e) => Err(KillError::Other(e.raw_os_error().unwrap_or(0))),
         }
     }
 
+    fn handle(&self) -> io::Result<ProcessHandle> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -387,6 +435,14 @@ impl ProcessExt for Process {
         &self.environ
     }
 
+    fn cmd_os(&self) -> Option<&[OsString]> {
+        None
+    }
+
+    fn environ_os(&self) -> Option<&[OsString]> {
+        None
+    }
+
     fn cwd(&self) -> &Path {
         self.cwd.as_path()
     }
@@ -415,10 +471,137 @@ impl ProcessExt for Process {
         self.start_time
     }
 
+    fn process_id(&self) -> ProcessId {
+        ProcessId {
+            pid: self.pid,
+            start_time: self.start_time(),
+            boot_id: 0,
+        }
+    }
+
     fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
 
+    fn disk_usage(&self) -> DiskUsage {
+        DiskUsage {
+            written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
+            total_written_bytes: self.written_bytes,
+            read_bytes: self.read_bytes.saturating_sub(self.old_read_bytes),
+            total_read_bytes: self.read_bytes,
+        }
+    }
+
+    fn cpu_time(&self) -> Option<CpuTime> {
+        // The times are in 100-nanosecond intervals.
+        Some(CpuTime {
+            total_user_time: Duration::from_nanos(self.old_user_cpu * 100),
+            user_time: Duration::from_nanos(self.user_cpu_delta * 100),
+            total_system_time: Duration::from_nanos(self.old_sys_cpu * 100),
+            system_time: Duration::from_nanos(self.sys_cpu_delta * 100),
+        })
+    }
+
+    fn memory_breakdown(&self) -> Option<MemoryBreakdown> {
+        None
+    }
+
+    fn thread_count(&self) -> Option<u64> {
+        None
+    }
+
+    fn activity(&self) -> Option<ProcessActivity> {
+        None
+    }
+
+    fn nice(&self) -> Option<i32> {
+        None
+    }
+
+    fn priority(&self) -> Option<i64> {
+        None
+    }
+
+    fn scheduling_policy(&self) -> Option<SchedulingPolicy> {
+        None
+    }
+
+    fn realtime_priority(&self) -> Option<u32> {
+        None
+    }
+
+    fn set_nice(&self, _nice: i32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn set_scheduling_policy(&self, _policy: SchedulingPolicy, _priority: u32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn cpu_affinity(&self) -> Option<Vec<usize>> {
+        None
+    }
+
+    fn set_cpu_affinity(&self, _processors: &[usize]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn user_id(&self) -> Option<Uid> {
+        None
+    }
+
+    fn group_id(&self) -> Option<Gid> {
+        None
+    }
+
+    fn real_user_id(&self) -> Option<Uid> {
+        None
+    }
+
+    fn real_group_id(&self) -> Option<Gid> {
+        None
+    }
+
+    fn threads(&self) -> &HashMap<Pid, Thread> {
+        &self.threads
+    }
+
+    fn open_files(&self) -> Option<Vec<OpenFile>> {
+        None
+    }
+
+    fn open_files_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn memory_details(&self) -> Option<MemoryDetails> {
+        None
+    }
+
+    fn memory_maps(&self) -> Option<Vec<MemoryMap>> {
+        None
+    }
+
+    fn resource_limits(&self) -> Option<Vec<ResourceLimit>> {
+        None
+    }
+
+    fn set_resource_limit(
+        &self,
+        _resource: Resource,
+        _soft: Option<Limit>,
+        _hard: Option<Limit>,
+    ) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn cgroups(&self) -> Option<Vec<Cgroup>> {
+        None
+    }
+
+    fn namespaces(&self) -> Option<Vec<Namespace>> {
+        None
+    }
 }
 
 impl Drop for Process {
@@ -735,6 +918,8 @@ pub(crate) fn compute_cpu_usage(p: &mut Process, nb_processors: u64, now: ULARGE
             / if old == 0 { 1 } else { old } as f32
             / nb_processors as f32
             * 100.;
+        p.user_cpu_delta = check_sub(*user.QuadPart(), p.old_user_cpu);
+        p.sys_cpu_delta = check_sub(*sys.QuadPart(), p.old_sys_cpu);
         p.old_cpu = *now.QuadPart();
         p.old_user_cpu = *user.QuadPart();
         p.old_sys_cpu = *sys.QuadPart();
@@ -745,6 +930,19 @@ pub fn get_handle(p: &Process) -> HANDLE {
     *p.handle
 }
 
+pub(crate) fn update_disk_usage(p: &mut Process) {
+    let mut counters = MaybeUninit::<IO_COUNTERS>::uninit();
+    let ret = unsafe { GetProcessIoCounters(*p.handle, counters.as_mut_ptr()) };
+    if ret == 0 {
+        sysinfo_debug!("GetProcessIoCounters call failed on process {}", p.pid());
+    } else {
+        let counters = unsafe { counters.assume_init() };
+        p.old_read_bytes = p.read_bytes;
+        p.old_written_bytes = p.written_bytes;
+        p.read_bytes = counters.ReadTransferCount;
+        p.written_bytes = counters.WriteTransferCount;
+    }
+}
 
 pub fn update_memory(p: &mut Process) {
     unsafe {
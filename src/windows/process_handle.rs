@@ -0,0 +1,37 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::io;
+use std::time::Duration;
+
+use KillError;
+use Pid;
+use ProcessHandleExt;
+
+/// Struct referring to a process which cannot be confused with another one reusing its pid.
+pub struct ProcessHandle;
+
+impl ProcessHandleExt for ProcessHandle {
+    fn pid(&self) -> Pid {
+        0
+    }
+
+    fn try_kill(&self, _signal: ::Signal) -> Result<(), KillError> {
+        Err(KillError::Unsupported)
+    }
+
+    fn has_exited(&self) -> bool {
+        false
+    }
+
+    fn wait(&self) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+
+    fn wait_timeout(&self, _timeout: Duration) -> io::Result<bool> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+}
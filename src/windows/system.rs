@@ -5,6 +5,7 @@
 //
 
 use sys::component::{self, Component};
+use sys::disk::Disk;
 use sys::processor::*;
 use sys::users::get_users;
 
@@ -13,16 +14,19 @@ use std::collections::HashMap;
 use std::mem::{size_of, zeroed};
 use std::time::SystemTime;
 
+use BlockDevices;
 use LoadAvg;
 use Networks;
 use Pid;
 use ProcessExt;
 use RefreshKind;
+use Socket;
 use SystemExt;
+use Uid;
 use User;
 
 use windows::process::{
-    compute_cpu_usage, get_handle, get_system_computation_time, update_memory,
+    compute_cpu_usage, get_handle, get_system_computation_time, update_disk_usage, update_memory,
     Process,
 };
 use windows::tools::*;
@@ -49,8 +53,10 @@ pub struct System {
     global_processor: Processor,
     processors: Vec<Processor>,
     components: Vec<Component>,
+    disks: Vec<Disk>,
     query: Option<Query>,
     networks: Networks,
+    block_devices: BlockDevices,
     boot_time: u64,
     users: Vec<User>,
 }
@@ -84,8 +90,10 @@ impl SystemExt for System {
             global_processor: Processor::new_with_values("Total CPU", vendor_id, brand, 0),
             processors,
             components: Vec::new(),
+            disks: Vec::with_capacity(2),
             query: Query::new(),
             networks: Networks::new(),
+            block_devices: BlockDevices::new(),
             boot_time: unsafe { boot_time() },
             users: Vec::new(),
         };
@@ -178,6 +186,7 @@ impl SystemExt for System {
         } else if let Some(mut p) = Process::new_from_pid(pid) {
             let system_time = get_system_computation_time();
             compute_cpu_usage(&mut p, self.processors.len() as u64, system_time);
+            update_disk_usage(&mut p);
             self.process_list.insert(pid, p);
             true
         } else {
@@ -246,6 +255,7 @@ impl SystemExt for System {
                             proc_.memory = (pi.WorkingSetSize as u64) / 1_000;
                             proc_.virtual_memory = (pi.VirtualSize as u64) / 1_000;
                             compute_cpu_usage(proc_, nb_processors, system_time);
+                            update_disk_usage(proc_);
                             proc_.updated = true;
                             return None;
                         }
@@ -262,6 +272,7 @@ impl SystemExt for System {
                             name,
                         );
                         compute_cpu_usage(&mut p, nb_processors, system_time);
+                        update_disk_usage(&mut p);
                         Some(p)
                     })
                     .collect::<Vec<_>>();
@@ -288,6 +299,10 @@ impl SystemExt for System {
         }
     }
 
+    fn refresh_disks_list(&mut self) {
+        self.disks = unsafe { get_disks() };
+    }
+
     fn refresh_users_list(&mut self) {
         self.users = unsafe { get_users() };
     }
@@ -345,10 +360,26 @@ impl SystemExt for System {
         &mut self.components
     }
 
+    fn get_disks(&self) -> &[Disk] {
+        &self.disks
+    }
+
+    fn get_disks_mut(&mut self) -> &mut [Disk] {
+        &mut self.disks
+    }
+
     fn get_users(&self) -> &[User] {
         &self.users
     }
 
+    fn get_user_by_id(&self, _uid: Uid) -> Option<&User> {
+        None
+    }
+
+    fn get_sockets(&self) -> Vec<Socket> {
+        Vec::new()
+    }
+
     fn get_networks(&self) -> &Networks {
         &self.networks
     }
@@ -357,6 +388,14 @@ impl SystemExt for System {
         &mut self.networks
     }
 
+    fn get_block_devices(&self) -> &BlockDevices {
+        &self.block_devices
+    }
+
+    fn get_block_devices_mut(&mut self) -> &mut BlockDevices {
+        &mut self.block_devices
+    }
+
     fn get_uptime(&self) -> u64 {
         unsafe { GetTickCount64() / 1000 }
     }
@@ -388,6 +427,7 @@ fn refresh_existing_process(s: &mut System, pid: Pid) -> bool {
             return false;
         }
         update_memory(entry);
+        update_disk_usage(entry);
         compute_cpu_usage(
             entry,
             s.processors.len() as u64,
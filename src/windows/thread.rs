@@ -0,0 +1,48 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+use std::io;
+
+use super::process::ProcessStatus;
+use Pid;
+use ThreadExt;
+
+/// Struct containing a thread's information.
+pub struct Thread;
+
+impl ThreadExt for Thread {
+    fn tid(&self) -> Pid {
+        0
+    }
+
+    fn name(&self) -> &str {
+        ""
+    }
+
+    fn status(&self) -> ProcessStatus {
+        ProcessStatus::Run
+    }
+
+    fn cpu_usage(&self) -> f32 {
+        0.
+    }
+
+    fn voluntary_context_switches(&self) -> u64 {
+        0
+    }
+
+    fn involuntary_context_switches(&self) -> u64 {
+        0
+    }
+
+    fn cpu_affinity(&self) -> Option<Vec<usize>> {
+        None
+    }
+
+    fn set_cpu_affinity(&self, _processors: &[usize]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "not supported"))
+    }
+}
@@ -4,7 +4,9 @@
 // Copyright (c) 2018 Guillaume Gomez
 //
 
+use windows::disk::{new_disk, Disk};
 use windows::processor::{self, Processor, Query};
+use DiskKind;
 
 use std::collections::HashMap;
 use std::ffi::OsStr;
@@ -23,10 +25,10 @@ use winapi::um::handleapi::INVALID_HANDLE_VALUE;
 use winapi::um::ioapiset::DeviceIoControl;
 use winapi::um::sysinfoapi::{GetSystemInfo, SYSTEM_INFO};
 use winapi::um::winbase::DRIVE_FIXED;
-use winapi::um::winioctl::{
-     IOCTL_STORAGE_QUERY_PROPERTY,
+use winapi::um::winioctl::IOCTL_STORAGE_QUERY_PROPERTY;
+use winapi::um::winnt::{
+    BOOLEAN, FILE_READ_ONLY_VOLUME, FILE_SHARE_READ, FILE_SHARE_WRITE, HANDLE,
 };
-use winapi::um::winnt::{BOOLEAN, FILE_SHARE_READ, FILE_SHARE_WRITE, HANDLE};
 
 pub struct KeyHandler {
     pub unique_id: String,
@@ -58,6 +60,137 @@ pub fn init_processors() -> (Vec<Processor>, String, String) {
     }
 }
 
+#[allow(non_snake_case)]
+#[repr(C)]
+struct STORAGE_PROPERTY_QUERY {
+    PropertyId: DWORD,
+    QueryType: DWORD,
+    AdditionalParameters: [BYTE; 1],
+}
+
+#[allow(non_snake_case)]
+#[repr(C)]
+struct DEVICE_SEEK_PENALTY_DESCRIPTOR {
+    Version: DWORD,
+    Size: DWORD,
+    IncursSeekPenalty: BOOLEAN,
+}
+
+// Values of the `STORAGE_PROPERTY_ID` and `STORAGE_QUERY_TYPE` enums.
+const STORAGE_DEVICE_SEEK_PENALTY_PROPERTY: DWORD = 7;
+const PROPERTY_STANDARD_QUERY: DWORD = 0;
+
+unsafe fn open_drive(drive_name: &[u16]) -> HANDLE {
+    CreateFileW(
+        drive_name.as_ptr(),
+        0,
+        FILE_SHARE_READ | FILE_SHARE_WRITE,
+        std::ptr::null_mut(),
+        OPEN_EXISTING,
+        0,
+        std::ptr::null_mut(),
+    )
+}
+
+/// If the drive incurs a seek penalty, it's very likely an HDD, otherwise an SSD.
+unsafe fn get_disk_kind(drive_name: &[u16]) -> DiskKind {
+    let handle = open_drive(drive_name);
+    if handle == INVALID_HANDLE_VALUE {
+        return DiskKind::Unknown(-1);
+    }
+    let mut query = STORAGE_PROPERTY_QUERY {
+        PropertyId: STORAGE_DEVICE_SEEK_PENALTY_PROPERTY,
+        QueryType: PROPERTY_STANDARD_QUERY,
+        AdditionalParameters: [0],
+    };
+    let mut descriptor: DEVICE_SEEK_PENALTY_DESCRIPTOR = zeroed();
+    let mut size = 0;
+    let kind = if DeviceIoControl(
+        handle,
+        IOCTL_STORAGE_QUERY_PROPERTY,
+        &mut query as *mut STORAGE_PROPERTY_QUERY as *mut c_void,
+        size_of::<STORAGE_PROPERTY_QUERY>() as DWORD,
+        &mut descriptor as *mut DEVICE_SEEK_PENALTY_DESCRIPTOR as *mut c_void,
+        size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as DWORD,
+        &mut size,
+        std::ptr::null_mut(),
+    ) == 0
+        || size != size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as DWORD
+    {
+        DiskKind::Unknown(-1)
+    } else if descriptor.IncursSeekPenalty == TRUE as BOOLEAN {
+        DiskKind::HDD
+    } else {
+        DiskKind::SSD
+    };
+    CloseHandle(handle);
+    kind
+}
+
+fn wide_str_len(s: &[u16]) -> usize {
+    s.iter().position(|x| *x == 0).unwrap_or(s.len())
+}
+
+pub unsafe fn get_disks() -> Vec<Disk> {
+    let drives = GetLogicalDrives();
+    if drives == 0 {
+        return Vec::new();
+    }
+
+    (0..size_of::<DWORD>() * 8)
+        .into_par_iter()
+        .filter_map(|x| {
+            if (drives >> x) & 1 == 0 {
+                return None;
+            }
+            let mount_point = [b'A' as u16 + x as u16, b':' as u16, b'\\' as u16, 0];
+            if GetDriveTypeW(mount_point.as_ptr()) != DRIVE_FIXED {
+                return None;
+            }
+            let mut name = [0u16; MAX_PATH + 1];
+            let mut file_system = [0u16; 32];
+            let mut flags = 0;
+            if GetVolumeInformationW(
+                mount_point.as_ptr(),
+                name.as_mut_ptr(),
+                name.len() as DWORD,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut flags,
+                file_system.as_mut_ptr(),
+                file_system.len() as DWORD,
+            ) == 0
+            {
+                return None;
+            }
+            let name = String::from_utf16_lossy(&name[..wide_str_len(&name)]);
+            let file_system: Vec<u8> = file_system[..wide_str_len(&file_system)]
+                .iter()
+                .map(|x| *x as u8)
+                .collect();
+            let drive_name = [
+                b'\\' as u16,
+                b'\\' as u16,
+                b'.' as u16,
+                b'\\' as u16,
+                b'A' as u16 + x as u16,
+                b':' as u16,
+                0,
+            ];
+            new_disk(
+                OsStr::new(&name),
+                &mount_point,
+                &file_system,
+                get_disk_kind(&drive_name),
+                vec![if flags & FILE_READ_ONLY_VOLUME != 0 {
+                    "ro".to_owned()
+                } else {
+                    "rw".to_owned()
+                }],
+            )
+        })
+        .collect::<Vec<_>>()
+}
 
 #[allow(non_snake_case)]
 pub unsafe fn load_symbols() -> HashMap<String, u32> {
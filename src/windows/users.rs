@@ -102,6 +102,9 @@ pub unsafe fn get_users() -> Vec<User> {
                     users.push(User {
                         name: to_str((*buf).usri1_name),
                         groups,
+                        // Windows users don't have a numeric id.
+                        uid: 0,
+                        gid: 0,
                     });
                 }
                 i = (*buf).usri1_next_index;
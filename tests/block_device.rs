@@ -0,0 +1,25 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2020 Guillaume Gomez
+//
+
+extern crate sysinfo;
+
+#[test]
+fn test_block_devices() {
+    use sysinfo::{BlockDeviceExt, BlockDevicesExt, SystemExt};
+
+    let s = sysinfo::System::new();
+    assert_eq!(s.get_block_devices().iter().count(), 0);
+    let mut s = sysinfo::System::new_all();
+    if cfg!(target_os = "linux") {
+        assert!(s.get_block_devices().iter().count() > 0);
+        assert!(s.get_block_devices().iter().any(|(_, d)| d.get_size() > 0));
+    }
+    s.refresh_block_devices();
+    for (_, device) in s.get_block_devices() {
+        assert!(device.get_total_reads_completed() >= device.get_reads_completed());
+        assert!(device.get_utilization() <= 100.);
+    }
+}
@@ -0,0 +1,23 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2017 Guillaume Gomez
+//
+
+extern crate sysinfo;
+
+#[test]
+fn test_disks() {
+    use sysinfo::{DiskExt, SystemExt};
+
+    let s = sysinfo::System::new();
+    assert!(s.get_disks().is_empty());
+    let s = sysinfo::System::new_all();
+    assert!(!s.get_disks().is_empty());
+    for disk in s.get_disks() {
+        assert!(disk.get_total_space() >= disk.get_available_space());
+        assert!(!disk.get_mount_point().as_os_str().is_empty());
+        assert!(disk.get_total_inodes() >= disk.get_free_inodes());
+        assert!(disk.has_mount_option("ro") || disk.has_mount_option("rw"));
+    }
+}
@@ -4,6 +4,8 @@
 // Copyright (c) 2018 Guillaume Gomez
 //
 
+#[cfg(feature = "regex")]
+extern crate regex;
 extern crate sysinfo;
 
 use sysinfo::ProcessExt;
@@ -73,6 +75,31 @@ fn unix_like_cmd() {
     assert_eq!(process.cmd(), &["sleep", "3"]);
 }
 
+#[test]
+fn test_process_disk_usage() {
+    use std::fs;
+    use std::fs::File;
+    use std::io::prelude::*;
+    use sysinfo::{get_current_pid, RefreshKind};
+
+    {
+        let mut file = File::create("test.txt").expect("failed to create file");
+        file.write_all(b"This is a test file\nwith test data.\n")
+            .expect("failed to write to file");
+        file.sync_all().expect("failed to sync file");
+    }
+    fs::remove_file("test.txt").expect("failed to remove file");
+    let system = sysinfo::System::new_with_specifics(RefreshKind::new().with_processes());
+    let p = system
+        .get_process(get_current_pid().expect("failed to get current pid"))
+        .expect("failed to get current process");
+
+    assert!(
+        p.disk_usage().total_written_bytes > 0,
+        "found {} total written bytes...",
+        p.disk_usage().total_written_bytes
+    );
+}
 
 #[test]
 fn cpu_usage_is_not_nan() {
@@ -96,3 +123,664 @@ fn cpu_usage_is_not_nan() {
     });
     assert!(checked > 0);
 }
+
+#[test]
+fn test_process_user_id() {
+    use sysinfo::{get_current_pid, RefreshKind, UserExt};
+
+    let system =
+        sysinfo::System::new_with_specifics(RefreshKind::new().with_processes().with_users_list());
+    let p = system
+        .get_process(get_current_pid().expect("failed to get current pid"))
+        .expect("failed to get current process");
+
+    if cfg!(windows) {
+        assert!(p.user_id().is_none());
+        assert!(p.group_id().is_none());
+    } else {
+        assert!(p.user_id().is_some());
+        assert!(p.group_id().is_some());
+        assert!(p.real_user_id().is_some());
+        assert!(p.real_group_id().is_some());
+        for user in system.get_users() {
+            assert_eq!(
+                system.get_user_by_id(user.get_uid()).map(|u| u.get_uid()),
+                Some(user.get_uid())
+            );
+        }
+        // Accounts without a login shell aren't in the users list but can still be resolved.
+        #[cfg(target_os = "linux")]
+        {
+            let passwd = std::fs::read_to_string("/etc/passwd").expect("failed to read passwd");
+            for line in passwd.lines() {
+                let parts = line.split(':').collect::<Vec<_>>();
+                if parts.len() < 7 || !parts[6].ends_with("/nologin") {
+                    continue;
+                }
+                let uid = parts[2].parse().expect("invalid uid");
+                let user = system.get_user_by_id(uid).expect("system user not found");
+                assert_eq!(user.get_name(), parts[0]);
+                assert!(system.get_users().iter().all(|u| u.get_name() != parts[0]));
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(not(windows))]
+fn test_process_tree() {
+    use sysinfo::get_current_pid;
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("3")
+        .spawn()
+        .unwrap();
+    let child_pid = child.id() as sysinfo::Pid;
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_processes();
+
+    assert!(s
+        .get_process_children(pid)
+        .iter()
+        .any(|p| p.pid() == child_pid));
+    assert!(s
+        .get_process_descendants(pid)
+        .iter()
+        .any(|p| p.pid() == child_pid));
+    let ancestors = s.get_process_ancestors(child_pid);
+    assert_eq!(ancestors.first().map(|p| p.pid()), Some(pid));
+    assert!(ancestors.iter().all(|p| p.pid() != child_pid));
+    assert!(s.get_process_tree_memory(pid) >= s.get_process(pid).unwrap().memory());
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_threads() {
+    use std::sync::mpsc;
+    use sysinfo::{get_current_pid, ThreadExt};
+
+    let (tx, rx) = mpsc::channel::<()>();
+    let (ready_tx, ready_rx) = mpsc::channel::<()>();
+    let handle = std::thread::Builder::new()
+        .name("sysinfo-thread".to_owned())
+        .spawn(move || {
+            // The thread name is set once the thread is started.
+            let _ = ready_tx.send(());
+            let _ = rx.recv();
+        })
+        .expect("failed to spawn thread");
+    ready_rx.recv().expect("failed to start thread");
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get current process");
+    assert!(p.threads().contains_key(&pid));
+    assert!(p
+        .threads()
+        .values()
+        .any(|thread| thread.name() == "sysinfo-thread"));
+    assert!(p.threads().iter().all(|(tid, thread)| *tid == thread.tid()));
+
+    tx.send(()).expect("failed to stop thread");
+    handle.join().expect("failed to join thread");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_open_files() {
+    use std::fs::File;
+    use sysinfo::{get_current_pid, FileKind};
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get current process");
+
+    let path = std::env::current_dir()
+        .expect("failed to get current directory")
+        .join("test_open_files.txt");
+    let file = File::create(&path).expect("failed to create file");
+    // The targets are resolved by the kernel so symlinks aren't kept.
+    let target = path.canonicalize().expect("failed to canonicalize path");
+    let files = p.open_files().expect("failed to get open files");
+    let count = p
+        .open_files_count()
+        .expect("failed to get open files count");
+    drop(file);
+    std::fs::remove_file(&path).expect("failed to remove file");
+
+    assert!(count > 0);
+    assert!(files
+        .iter()
+        .any(|f| f.target == target && f.kind == FileKind::File));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_memory_details() {
+    use sysinfo::get_current_pid;
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get current process");
+
+    let details = p.memory_details().expect("failed to get memory details");
+    assert!(details.rss > 0);
+    assert!(details.pss > 0 && details.pss <= details.rss);
+    assert!(details.uss() <= details.rss);
+
+    let maps = p.memory_maps().expect("failed to get memory maps");
+    assert!(maps.iter().all(|map| map.start < map.end));
+    assert!(maps.iter().any(|map| map.path == p.exe()));
+}
+
+#[test]
+fn test_process_memory_breakdown() {
+    use sysinfo::get_current_pid;
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get current process");
+
+    if cfg!(target_os = "linux") {
+        let breakdown = p
+            .memory_breakdown()
+            .expect("failed to get memory breakdown");
+        assert!(breakdown.peak_memory > 0);
+        assert!(breakdown.peak_virtual_memory >= breakdown.peak_memory);
+        assert!(breakdown.rss_anon + breakdown.rss_file > 0);
+    }
+    if cfg!(any(target_os = "linux", target_os = "macos")) {
+        assert!(p.thread_count().unwrap_or(0) > 0);
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_resource_limits() {
+    use sysinfo::{get_current_pid, Limit, Resource};
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get current process");
+
+    let limits = p.resource_limits().expect("failed to get resource limits");
+    let open_files = limits
+        .iter()
+        .find(|limit| limit.resource == Resource::OpenFiles)
+        .expect("no open files limit");
+    assert_ne!(open_files.soft, Limit::Unlimited);
+    assert!(open_files.soft <= open_files.hard);
+    // We set the same limits to not impact the other tests.
+    p.set_resource_limit(
+        Resource::OpenFiles,
+        Some(open_files.soft),
+        Some(open_files.hard),
+    )
+    .expect("failed to set resource limits");
+    // The current limits are kept if they aren't set.
+    p.set_resource_limit(Resource::OpenFiles, None, None)
+        .expect("failed to keep resource limits");
+    let limits = p.resource_limits().expect("failed to get resource limits");
+    assert!(limits.contains(open_files));
+    // The soft limit cannot be greater than the hard one.
+    let err = p
+        .set_resource_limit(
+            Resource::OpenFiles,
+            Some(Limit::Value(2)),
+            Some(Limit::Value(1)),
+        )
+        .expect_err("invalid limits were accepted");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_cgroups() {
+    use sysinfo::get_current_pid;
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get current process");
+
+    // The cgroup file might be empty if the kernel doesn't support cgroups.
+    let cgroups = p.cgroups().expect("failed to get cgroups");
+    assert!(cgroups.iter().all(|cgroup| cgroup.path.is_absolute()));
+    assert!(cgroups.iter().filter(|cgroup| cgroup.is_unified()).count() <= 1);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_namespaces() {
+    use sysinfo::{get_current_pid, NamespaceKind};
+
+    let pid = get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_processes();
+    let p = s.get_process(pid).expect("failed to get current process");
+
+    let namespaces = p.namespaces().expect("failed to get namespaces");
+    let net = namespaces
+        .iter()
+        .find(|namespace| namespace.kind == NamespaceKind::Net)
+        .expect("no network namespace");
+    assert!(s
+        .get_namespaces()
+        .get(net)
+        .map(|pids| pids.contains(&pid))
+        .unwrap_or(false));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_scheduling() {
+    use sysinfo::SchedulingPolicy;
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("3")
+        .spawn()
+        .unwrap();
+    let pid = child.id() as sysinfo::Pid;
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    {
+        let p = s.get_process(pid).expect("failed to get child process");
+        assert_eq!(p.scheduling_policy(), Some(SchedulingPolicy::Other));
+        assert_eq!(p.realtime_priority(), Some(0));
+        // Increasing the nice value and switching to a non real-time policy doesn't require any
+        // privilege.
+        p.set_nice(19).expect("failed to set nice value");
+        p.set_scheduling_policy(SchedulingPolicy::Batch, 0)
+            .expect("failed to set scheduling policy");
+        assert!(p
+            .set_scheduling_policy(SchedulingPolicy::Deadline, 0)
+            .is_err());
+    }
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get child process");
+    assert_eq!(p.nice(), Some(19));
+    assert_eq!(p.priority(), Some(39));
+    assert_eq!(p.scheduling_policy(), Some(SchedulingPolicy::Batch));
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_cpu_affinity() {
+    use sysinfo::ThreadExt;
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("3")
+        .spawn()
+        .unwrap();
+    let pid = child.id() as sysinfo::Pid;
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu();
+    s.refresh_process(pid);
+    let nb_processors = s.get_processors().len();
+    {
+        let p = s.get_process(pid).expect("failed to get child process");
+        let affinity = p.cpu_affinity().expect("failed to get affinity");
+        assert!(!affinity.is_empty());
+        assert!(affinity.iter().all(|&i| i < nb_processors));
+        p.set_cpu_affinity(&affinity[..1])
+            .expect("failed to set affinity");
+        assert_eq!(p.cpu_affinity(), Some(affinity[..1].to_vec()));
+        assert!(p.set_cpu_affinity(&[nb_processors]).is_err());
+    }
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get child process");
+    for thread in p.threads().values() {
+        assert!(thread.cpu_affinity().is_some());
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_activity() {
+    let mut s = sysinfo::System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_process(pid);
+    let first = s
+        .get_process(pid)
+        .expect("failed to get current process")
+        .activity()
+        .expect("failed to get activity");
+    assert!(first.total_minor_faults > 0);
+    // On the first refresh, the deltas are the totals.
+    assert_eq!(first.minor_faults, first.total_minor_faults);
+
+    // Touching new pages triggers minor faults.
+    let v = vec![1u8; 16 * 1024 * 1024];
+    assert_eq!(v.iter().map(|x| *x as u64).sum::<u64>(), v.len() as u64);
+    s.refresh_process(pid);
+    let second = s
+        .get_process(pid)
+        .expect("failed to get current process")
+        .activity()
+        .expect("failed to get activity");
+    assert!(second.total_minor_faults > first.total_minor_faults);
+    assert_eq!(
+        second.minor_faults,
+        second.total_minor_faults - first.total_minor_faults
+    );
+    assert!(second.total_voluntary_context_switches >= first.total_voluntary_context_switches);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_cpu_time() {
+    let mut s = sysinfo::System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_process(pid);
+    let first = s
+        .get_process(pid)
+        .expect("failed to get current process")
+        .cpu_time()
+        .expect("failed to get cpu time");
+
+    // Burn some CPU time so that it's visible with the clock tick precision.
+    let start = std::time::Instant::now();
+    let mut x = 0u64;
+    while start.elapsed() < std::time::Duration::from_millis(200) {
+        x = x.wrapping_add(1);
+    }
+    assert!(x > 0);
+
+    s.refresh_process(pid);
+    let second = s
+        .get_process(pid)
+        .expect("failed to get current process")
+        .cpu_time()
+        .expect("failed to get cpu time");
+    assert!(second.total_user_time + second.total_system_time > first.total_user_time);
+    assert_eq!(
+        second.user_time,
+        second.total_user_time - first.total_user_time
+    );
+    assert_eq!(
+        second.system_time,
+        second.total_system_time - first.total_system_time
+    );
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn test_process_try_kill() {
+    use sysinfo::{KillError, Signal};
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("3")
+        .spawn()
+        .unwrap();
+    let pid = child.id() as sysinfo::Pid;
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get child process");
+    assert_eq!(p.try_kill(Signal::Kill), Ok(()));
+    let _ = child.wait();
+    // The process has been reaped so it doesn't exist anymore.
+    assert_eq!(p.try_kill(Signal::Kill), Err(KillError::NoSuchProcess));
+    assert!(!p.kill(Signal::Kill));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_handle() {
+    use sysinfo::{KillError, ProcessHandleExt, Signal};
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("3")
+        .spawn()
+        .unwrap();
+    let pid = child.id() as sysinfo::Pid;
+    let mut s = sysinfo::System::new();
+    s.refresh_process(pid);
+    let p = s.get_process(pid).expect("failed to get child process");
+    let handle = p.handle().expect("failed to get process handle");
+    assert_eq!(handle.pid(), pid);
+    assert!(!handle.has_exited());
+    assert!(!handle
+        .wait_timeout(std::time::Duration::from_millis(50))
+        .unwrap());
+
+    assert_eq!(handle.try_kill(Signal::Kill), Ok(()));
+    // The process isn't reaped yet but it's considered as exited.
+    assert!(handle
+        .wait_timeout(std::time::Duration::from_secs(5))
+        .unwrap());
+    assert!(handle.has_exited());
+    handle.wait().unwrap();
+
+    let _ = child.wait();
+    assert_eq!(handle.try_kill(Signal::Kill), Err(KillError::NoSuchProcess));
+    // The process doesn't exist anymore so it's not possible to get a handle on it.
+    assert!(p.handle().is_err());
+}
+
+#[test]
+fn test_process_id() {
+    let mut s = sysinfo::System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_process(pid);
+    let id = s
+        .get_process(pid)
+        .expect("failed to get current process")
+        .process_id();
+    assert_eq!(id.pid, pid);
+    s.refresh_process(pid);
+    assert_eq!(
+        s.get_process(pid)
+            .expect("failed to get current process")
+            .process_id(),
+        id
+    );
+    #[cfg(target_os = "linux")]
+    {
+        assert_ne!(id.boot_id, 0);
+        let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap();
+        let start_time = stat
+            .rsplit(')')
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .nth(19)
+            .unwrap();
+        assert_eq!(id.start_time.to_string(), start_time);
+    }
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn test_process_query() {
+    use sysinfo::{ProcessQuery, ProcessSort};
+
+    let mut children = (0..3)
+        .map(|_| {
+            std::process::Command::new("sleep")
+                .arg("4.321")
+                .spawn()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = sysinfo::System::new();
+    s.refresh_processes();
+
+    let mut pids = children
+        .iter()
+        .map(|c| c.id() as sysinfo::Pid)
+        .collect::<Vec<_>>();
+    pids.sort();
+    let get_pids = |processes: Vec<&sysinfo::Process>| {
+        let mut found = processes.iter().map(|p| p.pid()).collect::<Vec<_>>();
+        found.sort();
+        found
+    };
+
+    let query = ProcessQuery::new()
+        .parent(pid)
+        .cmd_contains("4.321")
+        .name("sl??p");
+    assert_eq!(get_pids(s.find_processes(&query)), pids);
+    let query = ProcessQuery::new()
+        .parent(pid)
+        .cmd_contains("4.321")
+        .name("*le*");
+    assert_eq!(get_pids(s.find_processes(&query)), pids);
+    let query = ProcessQuery::new()
+        .parent(pid)
+        .cmd_contains("4.321")
+        .name("sleep*x");
+    assert!(s.find_processes(&query).is_empty());
+    #[cfg(feature = "regex")]
+    {
+        let query = ProcessQuery::new()
+            .parent(pid)
+            .cmd_contains("4.321")
+            .name_regex(regex::Regex::new("^sl.ep$").unwrap());
+        assert_eq!(get_pids(s.find_processes(&query)), pids);
+        let query = ProcessQuery::new()
+            .parent(pid)
+            .cmd_contains("4.321")
+            .exe_regex(regex::Regex::new("/s[a-z]+p$").unwrap());
+        assert_eq!(get_pids(s.find_processes(&query)), pids);
+        let query = ProcessQuery::new()
+            .parent(pid)
+            .cmd_contains("4.321")
+            .name_regex(regex::Regex::new("^leep").unwrap());
+        assert!(s.find_processes(&query).is_empty());
+    }
+    let first = pids[0];
+    let query = ProcessQuery::new()
+        .parent(pid)
+        .cmd_contains("4.321")
+        .filter(move |p| p.pid() != first);
+    assert_eq!(get_pids(s.find_processes(&query)), &pids[1..]);
+
+    let query = ProcessQuery::new().parent(pid).cmd_contains("4.321");
+    assert!(s.top_processes(&query, ProcessSort::Memory, 0).is_empty());
+    assert_eq!(
+        get_pids(s.top_processes(&query, ProcessSort::Memory, 10)),
+        pids
+    );
+    let top = s.top_processes(&ProcessQuery::new(), ProcessSort::Memory, 2);
+    assert_eq!(top.len(), 2);
+    assert!(top[0].memory() >= top[1].memory());
+    assert!(s
+        .get_processes()
+        .values()
+        .filter(|p| p.pid() != top[0].pid() && p.pid() != top[1].pid())
+        .all(|p| p.memory() <= top[1].memory()));
+
+    for child in children.iter_mut() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_full_cmd_and_environ() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let long_arg = "a".repeat(100_000);
+    let invalid_arg = OsStr::from_bytes(b"invalid\xff");
+    // Extra arguments given to `sh -c` are ignored.
+    let mut child = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("sleep 3; :")
+        .arg(&long_arg)
+        .arg(invalid_arg)
+        .env("SYSINFO_TEST_VAR", "a=b")
+        .spawn()
+        .unwrap();
+    let pid = child.id() as sysinfo::Pid;
+    let mut s = sysinfo::System::new();
+    // The command line might not be available yet right after the process started. It is only
+    // read when the process is added, hence the new `System`.
+    for _ in 0..100 {
+        s.refresh_process(pid);
+        if s.get_process(pid).map(|p| !p.cmd().is_empty()) == Some(true) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        s = sysinfo::System::new();
+    }
+    let p = s.get_process(pid).expect("failed to get child process");
+
+    // Nothing is truncated or dropped.
+    assert_eq!(p.cmd().len(), 5);
+    assert_eq!(p.cmd()[3], long_arg);
+    assert_eq!(p.cmd()[4], "invalid\u{FFFD}");
+    let cmd_os = p.cmd_os().expect("failed to get command line");
+    assert_eq!(cmd_os.len(), 5);
+    assert_eq!(cmd_os[4], invalid_arg);
+
+    assert_eq!(p.environ_var("SYSINFO_TEST_VAR"), Some("a=b"));
+    assert_eq!(p.environ_var("SYSINFO_UNKNOWN_VAR"), None);
+    assert_eq!(p.environ_map().get("SYSINFO_TEST_VAR"), Some(&"a=b"));
+    assert!(p
+        .environ_os()
+        .expect("failed to get environment")
+        .iter()
+        .any(|var| var == "SYSINFO_TEST_VAR=a=b"));
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_sockets() {
+    use sysinfo::{SocketProtocol, SocketState};
+
+    let tcp = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let udp = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let path = std::env::temp_dir().join(format!("sysinfo-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let unix = std::os::unix::net::UnixListener::bind(&path).unwrap();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+
+    let mut s = sysinfo::System::new();
+    s.refresh_processes();
+    let sockets = s.get_sockets();
+
+    let socket = sockets
+        .iter()
+        .find(|s| s.protocol == SocketProtocol::Tcp && s.local_address == tcp.local_addr().ok())
+        .expect("TCP socket not found");
+    assert_eq!(socket.state, SocketState::Listen);
+    assert!(socket.pids.contains(&pid));
+
+    let socket = sockets
+        .iter()
+        .find(|s| s.protocol == SocketProtocol::Udp && s.local_address == udp.local_addr().ok())
+        .expect("UDP socket not found");
+    assert!(socket.pids.contains(&pid));
+
+    let socket = sockets
+        .iter()
+        .find(|s| s.protocol == SocketProtocol::Unix && s.path.as_ref() == Some(&path))
+        .expect("Unix socket not found");
+    assert_eq!(socket.state, SocketState::Listen);
+    assert!(socket.pids.contains(&pid));
+
+    drop(unix);
+    let _ = std::fs::remove_file(&path);
+}